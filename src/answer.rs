@@ -0,0 +1,563 @@
+//! The server's reply to a submitted answer.
+
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::html::plain_text;
+use crate::solution::Answer;
+use crate::wait::{parse_cooldown, WaitTime};
+
+/// What the server said about a submitted answer.
+///
+/// ```
+/// use aoc_rs::answer::AnswerResponse;
+///
+/// let page = "<article><p>That's the right answer!  You are one gold star \
+///             closer to saving Christmas.</p></article>";
+/// assert_eq!(AnswerResponse::parse(page), Some(AnswerResponse::Correct));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnswerResponse {
+    Correct,
+    /// The answer was rejected. `guess` echoes what the server received,
+    /// when the page includes it.
+    WrongAnswer {
+        guess: Option<String>,
+        hint: Hint,
+    },
+    /// The part was already solved. The server does not say this directly;
+    /// see [`resolve_level`](AnswerResponse::resolve_level).
+    AlreadyCompleted,
+    /// The part is locked, or was already solved.
+    WrongLevel,
+    /// An answer was sent too recently; retry after the wait.
+    RateLimited(WaitTime),
+}
+
+/// Whether a wrong numeric answer was above or below the right one. Only
+/// some puzzles say.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+    None,
+}
+
+impl AnswerResponse {
+    /// Reads the response to a submission, as HTML or plain text. Returns
+    /// `None` if the page matches none of the known replies.
+    pub fn parse(page: &str) -> Option<AnswerResponse> {
+        let text = plain_text(page);
+        if text.contains("You gave an answer too recently") {
+            parse_cooldown(&text).map(AnswerResponse::RateLimited)
+        } else if text.contains("You don't seem to be solving the right level") {
+            Some(AnswerResponse::WrongLevel)
+        } else if text.contains("That's not the right answer") {
+            Some(AnswerResponse::WrongAnswer {
+                guess: guess(&text),
+                hint: hint(&text),
+            })
+        } else if text.contains("That's the right answer") {
+            Some(AnswerResponse::Correct)
+        } else {
+            None
+        }
+    }
+
+    /// Splits [`WrongLevel`](AnswerResponse::WrongLevel) into
+    /// [`AlreadyCompleted`](AnswerResponse::AlreadyCompleted) when the
+    /// caller knows the part is solved. The server replies "You don't seem
+    /// to be solving the right level. Did you already complete it?" in both
+    /// cases, so the page alone cannot tell them apart. Other responses are
+    /// returned unchanged.
+    ///
+    /// ```
+    /// use aoc_rs::answer::AnswerResponse;
+    ///
+    /// let reply = AnswerResponse::WrongLevel;
+    /// assert_eq!(reply.clone().resolve_level(true), AnswerResponse::AlreadyCompleted);
+    /// assert_eq!(reply.resolve_level(false), AnswerResponse::WrongLevel);
+    /// ```
+    pub fn resolve_level(self, part_solved: bool) -> AnswerResponse {
+        match self {
+            AnswerResponse::WrongLevel if part_solved => AnswerResponse::AlreadyCompleted,
+            other => other,
+        }
+    }
+}
+
+/// The range a numeric answer must lie in, narrowed by the too-high and
+/// too-low hints on earlier wrong guesses for one puzzle part.
+///
+/// ```
+/// use aoc_rs::answer::{AnswerBounds, Hint};
+///
+/// let mut bounds = AnswerBounds::new();
+/// bounds.record(50, Hint::TooLow);
+/// assert_eq!(bounds.predict(10), Hint::TooLow);
+/// assert_eq!(bounds.predict(51), Hint::None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AnswerBounds {
+    above: Option<i128>,
+    below: Option<i128>,
+}
+
+impl AnswerBounds {
+    pub fn new() -> Self {
+        AnswerBounds::default()
+    }
+
+    /// The largest guess known to be too low; the answer is above it.
+    pub fn above(&self) -> Option<i128> {
+        self.above
+    }
+
+    /// The smallest guess known to be too high; the answer is below it.
+    pub fn below(&self) -> Option<i128> {
+        self.below
+    }
+
+    /// Narrows the range with the hint given for `guess`.
+    pub fn record(&mut self, guess: i128, hint: Hint) {
+        match hint {
+            Hint::TooLow => self.above = Some(self.above.map_or(guess, |a| a.max(guess))),
+            Hint::TooHigh => self.below = Some(self.below.map_or(guess, |b| b.min(guess))),
+            Hint::None => {}
+        }
+    }
+
+    /// Records a wrong answer's hint, if its guess is a number. Returns
+    /// whether the bounds were updated.
+    pub fn record_response(&mut self, response: &AnswerResponse) -> bool {
+        let AnswerResponse::WrongAnswer {
+            guess: Some(guess),
+            hint,
+        } = response
+        else {
+            return false;
+        };
+        match guess.parse() {
+            Ok(guess) if *hint != Hint::None => {
+                self.record(guess, *hint);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The hint the server is already known to give for `value`, or
+    /// [`Hint::None`] if it is still possible. Callers can refuse or warn
+    /// before submitting anything this rules out.
+    pub fn predict(&self, value: i128) -> Hint {
+        if self.above.is_some_and(|above| value <= above) {
+            Hint::TooLow
+        } else if self.below.is_some_and(|below| value >= below) {
+            Hint::TooHigh
+        } else {
+            Hint::None
+        }
+    }
+}
+
+/// One submitted answer and the server's reply.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub answer: Answer,
+    pub response: AnswerResponse,
+    pub at: SystemTime,
+}
+
+/// The answers submitted for one puzzle part, so a rejected answer is
+/// never sent twice.
+///
+/// ```
+/// use aoc_rs::answer::{AnswerResponse, GuessHistory, Hint};
+///
+/// let mut history = GuessHistory::new();
+/// let wrong = AnswerResponse::WrongAnswer { guess: None, hint: Hint::TooLow };
+/// history.record(50.into(), wrong.clone());
+///
+/// assert_eq!(history.known_wrong(&50.into()), Some(&wrong));
+/// assert_eq!(history.known_wrong(&51.into()), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GuessHistory {
+    guesses: Vec<Guess>,
+}
+
+impl GuessHistory {
+    pub fn new() -> Self {
+        GuessHistory::default()
+    }
+
+    /// Records a submission and its reply, timestamped now.
+    pub fn record(&mut self, answer: Answer, response: AnswerResponse) {
+        self.guesses.push(Guess {
+            answer,
+            response,
+            at: SystemTime::now(),
+        });
+    }
+
+    /// Every recorded submission, oldest first.
+    pub fn guesses(&self) -> &[Guess] {
+        &self.guesses
+    }
+
+    /// The earlier reply if `answer` was already rejected. Check this
+    /// before submitting: sending it again would only cost a cooldown.
+    pub fn known_wrong(&self, answer: &Answer) -> Option<&AnswerResponse> {
+        self.guesses
+            .iter()
+            .find(|guess| {
+                guess.answer == *answer
+                    && matches!(guess.response, AnswerResponse::WrongAnswer { .. })
+            })
+            .map(|guess| &guess.response)
+    }
+
+    /// The bounds implied by the hints on the numeric wrong answers.
+    pub fn bounds(&self) -> AnswerBounds {
+        let mut bounds = AnswerBounds::new();
+        for guess in &self.guesses {
+            if let AnswerResponse::WrongAnswer { hint, .. } = guess.response {
+                if let Ok(value) = guess.answer.as_str().parse() {
+                    bounds.record(value, hint);
+                }
+            }
+        }
+        bounds
+    }
+}
+
+/// Runs `submit` until its reply is not
+/// [`RateLimited`](AnswerResponse::RateLimited), sleeping for the reported
+/// wait plus `jitter` before each retry. Makes at most `max_attempts` calls
+/// (at least one), and returns the last reply or the first error.
+///
+/// Only the rate limit is retried. A wrong answer is returned at once, since
+/// sending the same answer again cannot succeed.
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use aoc_rs::answer::{submit_with_retry, AnswerResponse};
+///
+/// # fn post_answer() -> Result<String, std::io::Error> { unimplemented!() }
+/// let reply = submit_with_retry(3, Duration::from_secs(2), || {
+///     let page = post_answer()?;
+///     Ok::<_, std::io::Error>(AnswerResponse::parse(&page).expect("unexpected reply"))
+/// });
+/// ```
+pub fn submit_with_retry<E>(
+    max_attempts: usize,
+    jitter: Duration,
+    submit: impl FnMut() -> Result<AnswerResponse, E>,
+) -> Result<AnswerResponse, E> {
+    retry_with(max_attempts, jitter, submit, thread::sleep)
+}
+
+fn retry_with<E>(
+    max_attempts: usize,
+    jitter: Duration,
+    mut submit: impl FnMut() -> Result<AnswerResponse, E>,
+    mut sleep: impl FnMut(Duration),
+) -> Result<AnswerResponse, E> {
+    let mut reply = submit()?;
+    for _ in 1..max_attempts {
+        let AnswerResponse::RateLimited(wait) = reply else {
+            break;
+        };
+        sleep((wait + jitter).as_duration());
+        reply = submit()?;
+    }
+    Ok(reply)
+}
+
+/// The value in the `(You guessed 32.)` note on a wrong-answer page.
+fn guess(text: &str) -> Option<String> {
+    let start = text.find("(You guessed ")? + "(You guessed ".len();
+    let len = text[start..].find(".)")?;
+    Some(text[start..start + len].to_string())
+}
+
+fn hint(text: &str) -> Hint {
+    if text.contains("your answer is too high") {
+        Hint::TooHigh
+    } else if text.contains("your answer is too low") {
+        Hint::TooLow
+    } else {
+        Hint::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CORRECT: &str = "<main>\n<article><p>That's the right answer!  You are one gold star \
+        closer to finding the sleigh keys. <a href=\"/2021/day/1#part2\">[Continue to Part \
+        Two]</a></p></article>\n</main>";
+
+    const WRONG: &str = "<main>\n<article><p>That's not the right answer.  If you're stuck, \
+        make sure you're using the full input data; there are also some general tips on the \
+        <a href=\"/2021/about\">about page</a>, or you can ask for hints on the \
+        <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  \
+        (You guessed <span style=\"white-space:nowrap;\"><code>1234</code></span>.)  Please \
+        wait one minute before trying again. <a href=\"/2021/day/1\">[Return to Day \
+        1]</a></p></article>\n</main>";
+
+    const TOO_RECENT: &str = "<main>\n<article><p>You gave an answer too recently; you have \
+        to wait after submitting an answer before trying again.  You have 1m 5s left to wait. \
+        <a href=\"/2021/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+
+    const WRONG_LEVEL: &str = "<main>\n<article><p>You don't seem to be solving the right \
+        level.  Did you already complete it? <a href=\"/2021/day/1\">[Return to Day \
+        1]</a></p></article>\n</main>";
+
+    #[test]
+    fn correct() {
+        assert_eq!(
+            AnswerResponse::parse(CORRECT),
+            Some(AnswerResponse::Correct)
+        );
+    }
+
+    #[test]
+    fn wrong_answer() {
+        assert_eq!(
+            AnswerResponse::parse(WRONG),
+            Some(AnswerResponse::WrongAnswer {
+                guess: Some("1234".to_string()),
+                hint: Hint::None,
+            })
+        );
+    }
+
+    #[test]
+    fn too_high_and_too_low() {
+        let page = |side| {
+            format!(
+                "<p>That's not the right answer; your answer is too {side}.  (You guessed \
+                 <span style=\"white-space:nowrap;\"><code>50</code></span>.)</p>"
+            )
+        };
+        let wrong = |hint| {
+            Some(AnswerResponse::WrongAnswer {
+                guess: Some("50".to_string()),
+                hint,
+            })
+        };
+        assert_eq!(AnswerResponse::parse(&page("high")), wrong(Hint::TooHigh));
+        assert_eq!(AnswerResponse::parse(&page("low")), wrong(Hint::TooLow));
+    }
+
+    #[test]
+    fn guess_is_optional() {
+        assert_eq!(
+            AnswerResponse::parse("That's not the right answer.  Please wait one minute."),
+            Some(AnswerResponse::WrongAnswer {
+                guess: None,
+                hint: Hint::None,
+            })
+        );
+        assert_eq!(
+            AnswerResponse::parse("That's not the right answer. (You guessed 3.5.)"),
+            Some(AnswerResponse::WrongAnswer {
+                guess: Some("3.5".to_string()),
+                hint: Hint::None,
+            })
+        );
+    }
+
+    #[test]
+    fn rate_limited() {
+        assert_eq!(
+            AnswerResponse::parse(TOO_RECENT),
+            Some(AnswerResponse::RateLimited(WaitTime::from_secs(65)))
+        );
+        assert_eq!(
+            AnswerResponse::parse(
+                "You gave an answer too recently; you have to wait after submitting an \
+                 answer before trying again.  You have 42s left to wait."
+            ),
+            Some(AnswerResponse::RateLimited(WaitTime::from_secs(42)))
+        );
+    }
+
+    #[test]
+    fn wrong_level() {
+        assert_eq!(
+            AnswerResponse::parse(WRONG_LEVEL),
+            Some(AnswerResponse::WrongLevel)
+        );
+    }
+
+    #[test]
+    fn already_completed_needs_caller_knowledge() {
+        let reply = AnswerResponse::parse(WRONG_LEVEL).unwrap();
+        assert_eq!(
+            reply.clone().resolve_level(true),
+            AnswerResponse::AlreadyCompleted
+        );
+        assert_eq!(reply.resolve_level(false), AnswerResponse::WrongLevel);
+        assert_eq!(
+            AnswerResponse::Correct.resolve_level(true),
+            AnswerResponse::Correct
+        );
+    }
+
+    #[test]
+    fn bounds_narrow_with_hints() {
+        let mut bounds = AnswerBounds::new();
+        assert_eq!(bounds.predict(i128::MIN), Hint::None);
+
+        bounds.record(50, Hint::TooLow);
+        bounds.record(20, Hint::TooLow);
+        bounds.record(90, Hint::TooHigh);
+        bounds.record(70, Hint::TooHigh);
+        bounds.record(60, Hint::None);
+        assert_eq!((bounds.above(), bounds.below()), (Some(50), Some(70)));
+
+        assert_eq!(bounds.predict(50), Hint::TooLow);
+        assert_eq!(bounds.predict(51), Hint::None);
+        assert_eq!(bounds.predict(69), Hint::None);
+        assert_eq!(bounds.predict(70), Hint::TooHigh);
+    }
+
+    #[test]
+    fn bounds_from_responses() {
+        let mut bounds = AnswerBounds::new();
+        let too_high = AnswerResponse::parse(
+            "That's not the right answer; your answer is too high. (You guessed 1234.)",
+        )
+        .unwrap();
+        assert!(bounds.record_response(&too_high));
+        assert_eq!(bounds.below(), Some(1234));
+
+        let text_guess = AnswerResponse::WrongAnswer {
+            guess: Some("EFUGLPAP".to_string()),
+            hint: Hint::TooLow,
+        };
+        assert!(!bounds.record_response(&text_guess));
+        assert!(!bounds.record_response(&AnswerResponse::Correct));
+        assert_eq!(bounds.above(), None);
+    }
+
+    #[test]
+    fn history_flags_repeated_wrong_answers() {
+        let mut history = GuessHistory::new();
+        let too_high = AnswerResponse::WrongAnswer {
+            guess: Some("90".to_string()),
+            hint: Hint::TooHigh,
+        };
+        history.record(90.into(), too_high.clone());
+        history.record(
+            80.into(),
+            AnswerResponse::RateLimited(WaitTime::from_secs(30)),
+        );
+        history.record(80.into(), AnswerResponse::Correct);
+
+        assert_eq!(history.guesses().len(), 3);
+        assert_eq!(history.known_wrong(&90.into()), Some(&too_high));
+        // Rate-limited and accepted answers are not known to be wrong.
+        assert_eq!(history.known_wrong(&80.into()), None);
+    }
+
+    #[test]
+    fn history_bounds_from_numeric_guesses() {
+        let mut history = GuessHistory::new();
+        let wrong = |hint| AnswerResponse::WrongAnswer { guess: None, hint };
+        history.record(10.into(), wrong(Hint::TooLow));
+        history.record(90.into(), wrong(Hint::TooHigh));
+        history.record("ABC".into(), wrong(Hint::TooLow));
+
+        let bounds = history.bounds();
+        assert_eq!((bounds.above(), bounds.below()), (Some(10), Some(90)));
+    }
+
+    #[test]
+    fn retries_only_while_rate_limited() {
+        let mut replies = vec![
+            AnswerResponse::Correct,
+            AnswerResponse::RateLimited(WaitTime::from_secs(5)),
+            AnswerResponse::RateLimited(WaitTime::from_secs(65)),
+        ];
+        let mut slept = Vec::new();
+        let reply = retry_with(
+            5,
+            Duration::from_secs(1),
+            || Ok::<_, ()>(replies.pop().unwrap()),
+            |wait| slept.push(wait.as_secs()),
+        );
+        assert_eq!(reply, Ok(AnswerResponse::Correct));
+        assert_eq!(slept, vec![66, 6]);
+    }
+
+    #[test]
+    fn retry_gives_up_after_max_attempts() {
+        let limited = AnswerResponse::RateLimited(WaitTime::from_secs(30));
+        let mut calls = 0;
+        let reply = retry_with(
+            2,
+            Duration::ZERO,
+            || {
+                calls += 1;
+                Ok::<_, ()>(limited.clone())
+            },
+            |_| {},
+        );
+        assert_eq!((reply, calls), (Ok(limited.clone()), 2));
+
+        calls = 0;
+        let once = retry_with(
+            0,
+            Duration::ZERO,
+            || {
+                calls += 1;
+                Ok::<_, ()>(limited.clone())
+            },
+            |_| {},
+        );
+        assert_eq!((once, calls), (Ok(limited), 1));
+    }
+
+    #[test]
+    fn retry_stops_on_wrong_answer_and_errors() {
+        let wrong = AnswerResponse::WrongAnswer {
+            guess: None,
+            hint: Hint::TooLow,
+        };
+        let mut slept = false;
+        let reply = retry_with(
+            3,
+            Duration::ZERO,
+            || Ok::<_, ()>(wrong.clone()),
+            |_| slept = true,
+        );
+        assert_eq!(reply, Ok(wrong));
+        assert!(!slept);
+
+        let failed = retry_with(
+            3,
+            Duration::ZERO,
+            || Err::<AnswerResponse, _>("offline"),
+            |_| {},
+        );
+        assert_eq!(failed, Err("offline"));
+    }
+
+    #[test]
+    fn unknown_page() {
+        assert_eq!(AnswerResponse::parse("<html>404 Not Found</html>"), None);
+        assert_eq!(AnswerResponse::parse(""), None);
+    }
+
+    #[test]
+    fn entities_and_markup_are_ignored() {
+        assert_eq!(
+            AnswerResponse::parse("<p>That&#39;s <em>the</em>\n right answer!</p>"),
+            Some(AnswerResponse::Correct)
+        );
+    }
+}
@@ -0,0 +1,227 @@
+//! Timing registered solutions, for the "whole event in under a second"
+//! report.
+//!
+//! [`bench`] runs one part repeatedly after a few warmup runs, and
+//! [`markdown_table`] lays out the timings of many parts.
+
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::date::{Day, Year};
+use crate::solution::{Part, Registry};
+
+/// How many times to run a part before and while timing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed runs, to warm caches and the allocator.
+    pub warmups: usize,
+    /// Timed runs. At least one is always made.
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmups: 3,
+            iterations: 20,
+        }
+    }
+}
+
+/// Summary statistics of a set of timed runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Population standard deviation.
+    pub stddev: Duration,
+}
+
+impl Timings {
+    /// Summarises `samples`, or `None` if there are none.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use aoc_rs::bench::Timings;
+    ///
+    /// let ms = Duration::from_millis;
+    /// let timings = Timings::from_samples(&[ms(4), ms(1), ms(2), ms(5)]).unwrap();
+    /// assert_eq!(timings.min, ms(1));
+    /// assert_eq!(timings.median, ms(3));
+    /// assert_eq!(timings.mean, ms(3));
+    /// ```
+    pub fn from_samples(samples: &[Duration]) -> Option<Timings> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let min = *sorted.first()?;
+
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Some(Timings {
+            iterations: n,
+            min,
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+/// Times one part of a registered puzzle, parsing included, or returns
+/// `None` if no solver is registered for it.
+pub fn bench(
+    registry: &Registry,
+    year: Year,
+    day: Day,
+    part: Part,
+    input: &str,
+    config: &BenchConfig,
+) -> Option<Timings> {
+    for _ in 0..config.warmups {
+        black_box(registry.run_part(year, day, part, black_box(input))?);
+    }
+
+    let mut samples = Vec::with_capacity(config.iterations.max(1));
+    for _ in 0..config.iterations.max(1) {
+        let start = Instant::now();
+        black_box(registry.run_part(year, day, part, black_box(input))?);
+        samples.push(start.elapsed());
+    }
+    Timings::from_samples(&samples)
+}
+
+/// One line of a [`markdown_table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchRow {
+    pub year: Year,
+    pub day: Day,
+    pub part: Part,
+    pub timings: Timings,
+}
+
+/// A Markdown table with one line per row and a final line summing the
+/// medians.
+///
+/// ```text
+/// | Year | Day | Part | Min | Median | Mean | Std dev |
+/// |------|----:|-----:|----:|-------:|-----:|--------:|
+/// | 2021 | 1 | 1 | 1.00ms | 2.00ms | 2.50ms | 250.00µs |
+/// | **Total** | | | | **2.00ms** | | |
+/// ```
+pub fn markdown_table(rows: &[BenchRow]) -> String {
+    let mut table = String::from(
+        "| Year | Day | Part | Min | Median | Mean | Std dev |\n\
+         |------|----:|-----:|----:|-------:|-----:|--------:|\n",
+    );
+    for row in rows {
+        let part = match row.part {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+        let t = &row.timings;
+        let _ = writeln!(
+            table,
+            "| {} | {} | {part} | {:.2?} | {:.2?} | {:.2?} | {:.2?} |",
+            row.year, row.day, t.min, t.median, t.mean, t.stddev
+        );
+    }
+    let total: Duration = rows.iter().map(|row| row.timings.median).sum();
+    let _ = writeln!(table, "| **Total** | | | | **{total:.2?}** | | |");
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Answer, Solution};
+
+    struct Count;
+
+    impl Solution for Count {
+        type Parsed = usize;
+
+        fn parse(input: &str) -> usize {
+            input.lines().count()
+        }
+
+        fn part1(lines: &usize) -> Answer {
+            (*lines).into()
+        }
+
+        fn part2(lines: &usize) -> Answer {
+            (lines * 2).into()
+        }
+    }
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn statistics() {
+        let timings =
+            Timings::from_samples(&[ms(2), ms(4), ms(4), ms(4), ms(5), ms(5), ms(7), ms(9)])
+                .unwrap();
+        assert_eq!(timings.iterations, 8);
+        assert_eq!(timings.min, ms(2));
+        assert_eq!(timings.median, ms(4) + Duration::from_micros(500));
+        assert_eq!(timings.mean, ms(5));
+        assert_eq!(timings.stddev, ms(2));
+
+        let single = Timings::from_samples(&[ms(3)]).unwrap();
+        assert_eq!((single.median, single.stddev), (ms(3), Duration::ZERO));
+        assert_eq!(Timings::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn bench_runs_registered_parts() {
+        let (year, day) = (Year::new(2015).unwrap(), Day::new(1).unwrap());
+        let mut registry = Registry::new();
+        registry.register::<Count>(year, day);
+
+        let config = BenchConfig {
+            warmups: 0,
+            iterations: 0,
+        };
+        let timings = bench(&registry, year, day, Part::Two, "a\nb", &config).unwrap();
+        assert_eq!(timings.iterations, 1);
+
+        let other = Day::new(2).unwrap();
+        assert_eq!(bench(&registry, year, other, Part::One, "", &config), None);
+    }
+
+    #[test]
+    fn table_sums_medians() {
+        let timings = Timings::from_samples(&[ms(1), ms(3)]).unwrap();
+        let row = |day, part| BenchRow {
+            year: Year::new(2021).unwrap(),
+            day: Day::new(day).unwrap(),
+            part,
+            timings,
+        };
+        let table = markdown_table(&[row(1, Part::One), row(1, Part::Two)]);
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            lines[2],
+            "| 2021 | 1 | 1 | 1.00ms | 2.00ms | 2.00ms | 1.00ms |"
+        );
+        assert_eq!(
+            lines[3],
+            "| 2021 | 1 | 2 | 1.00ms | 2.00ms | 2.00ms | 1.00ms |"
+        );
+        assert_eq!(lines[4], "| **Total** | | | | **4.00ms** | | |");
+    }
+}
@@ -0,0 +1,354 @@
+//! Permutations, combinations and products of small collections, for the
+//! puzzles that are solved by trying every ordering.
+//!
+//! The iterators work over slices and yield owned `Vec`s of cloned items.
+//! Their `size_hint` is exact whenever the total count fits in a `usize`, and
+//! `(0, None)` otherwise.
+
+/// Every ordering of `items`, in lexicographic order of positions.
+///
+/// Equal items are not deduplicated, so `n` items always give `n!` orderings.
+///
+/// ```
+/// use aoc_rs::combinatorics::permutations;
+///
+/// let all: Vec<_> = permutations(&['a', 'b', 'c']).collect();
+/// assert_eq!(all.len(), 6);
+/// assert_eq!(all[1], vec!['a', 'c', 'b']);
+/// ```
+pub fn permutations<T: Clone>(items: &[T]) -> Permutations<'_, T> {
+    Permutations {
+        items,
+        indices: (0..items.len()).collect(),
+        remaining: (1..=items.len()).try_fold(1usize, |acc, n| acc.checked_mul(n)),
+        done: false,
+    }
+}
+
+/// Every `k`-element subset of `items`, keeping their relative order.
+///
+/// ```
+/// use aoc_rs::combinatorics::combinations;
+///
+/// let pairs: Vec<_> = combinations(&[1, 2, 3], 2).collect();
+/// assert_eq!(pairs, vec![vec![1, 2], vec![1, 3], vec![2, 3]]);
+/// ```
+pub fn combinations<T: Clone>(items: &[T], k: usize) -> Combinations<'_, T> {
+    Combinations {
+        items,
+        indices: (0..k).collect(),
+        remaining: binomial(items.len(), k),
+        done: k > items.len(),
+    }
+}
+
+/// Every pair taking one item from `a` and one from `b`, with `b` varying
+/// fastest.
+///
+/// ```
+/// use aoc_rs::combinatorics::cartesian_product;
+///
+/// let pairs = cartesian_product(&[1, 2], &['a', 'b', 'c']);
+/// assert_eq!(pairs.size_hint(), (6, Some(6)));
+/// ```
+pub fn cartesian_product<'a, A: Clone, B: Clone>(
+    a: &'a [A],
+    b: &'a [B],
+) -> CartesianProduct<'a, A, B> {
+    CartesianProduct {
+        a,
+        b,
+        i: 0,
+        j: 0,
+        remaining: a.len().checked_mul(b.len()),
+        done: a.is_empty() || b.is_empty(),
+    }
+}
+
+/// Every sequence of `repeat` items drawn from `items` with replacement,
+/// like nested loops over the same collection.
+///
+/// ```
+/// use aoc_rs::combinatorics::product;
+///
+/// let ops: Vec<_> = product(&['+', '*'], 2).collect();
+/// assert_eq!(ops, vec![vec!['+', '+'], vec!['+', '*'], vec!['*', '+'], vec!['*', '*']]);
+/// ```
+pub fn product<T: Clone>(items: &[T], repeat: usize) -> Product<'_, T> {
+    Product {
+        items,
+        indices: vec![0; repeat],
+        remaining: u32::try_from(repeat)
+            .ok()
+            .and_then(|repeat| items.len().checked_pow(repeat)),
+        done: items.is_empty() && repeat > 0,
+    }
+}
+
+fn binomial(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+    // C(n, i) grows with i up to n / 2, so every partial value is at most the
+    // result, and each product below is at most usize::MAX^2, which fits in
+    // a u128.
+    let k = k.min(n - k);
+    (0..k).try_fold(1usize, |acc, i| {
+        let next = acc as u128 * (n - i) as u128 / (i + 1) as u128;
+        usize::try_from(next).ok()
+    })
+}
+
+fn exact_hint(remaining: Option<usize>, done: bool) -> (usize, Option<usize>) {
+    match (done, remaining) {
+        (true, _) => (0, Some(0)),
+        (false, Some(n)) => (n, Some(n)),
+        (false, None) => (0, None),
+    }
+}
+
+fn pick<T: Clone>(items: &[T], indices: &[usize]) -> Vec<T> {
+    indices.iter().map(|&i| items[i].clone()).collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct Permutations<'a, T> {
+    items: &'a [T],
+    indices: Vec<usize>,
+    remaining: Option<usize>,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for Permutations<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+
+        let current = pick(self.items, &self.indices);
+        self.remaining = self.remaining.map(|n| n - 1);
+
+        match self.indices.windows(2).rposition(|w| w[0] < w[1]) {
+            Some(i) => {
+                let j = self
+                    .indices
+                    .iter()
+                    .rposition(|&x| x > self.indices[i])
+                    .unwrap();
+                self.indices.swap(i, j);
+                self.indices[i + 1..].reverse();
+            }
+            None => self.done = true,
+        }
+
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        exact_hint(self.remaining, self.done)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Combinations<'a, T> {
+    items: &'a [T],
+    indices: Vec<usize>,
+    remaining: Option<usize>,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for Combinations<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+
+        let current = pick(self.items, &self.indices);
+        self.remaining = self.remaining.map(|n| n - 1);
+
+        let (n, k) = (self.items.len(), self.indices.len());
+        match (0..k).rev().find(|&i| self.indices[i] != i + n - k) {
+            Some(i) => {
+                self.indices[i] += 1;
+                for j in i + 1..k {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        exact_hint(self.remaining, self.done)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Product<'a, T> {
+    items: &'a [T],
+    indices: Vec<usize>,
+    remaining: Option<usize>,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for Product<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+
+        let current = pick(self.items, &self.indices);
+        self.remaining = self.remaining.map(|n| n - 1);
+
+        match self.indices.iter().rposition(|&i| i + 1 < self.items.len()) {
+            Some(i) => {
+                self.indices[i] += 1;
+                self.indices[i + 1..].fill(0);
+            }
+            None => self.done = true,
+        }
+
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        exact_hint(self.remaining, self.done)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CartesianProduct<'a, A, B> {
+    a: &'a [A],
+    b: &'a [B],
+    i: usize,
+    j: usize,
+    remaining: Option<usize>,
+    done: bool,
+}
+
+impl<A: Clone, B: Clone> Iterator for CartesianProduct<'_, A, B> {
+    type Item = (A, B);
+
+    fn next(&mut self) -> Option<(A, B)> {
+        if self.done {
+            return None;
+        }
+
+        let current = (self.a[self.i].clone(), self.b[self.j].clone());
+        self.remaining = self.remaining.map(|n| n - 1);
+
+        self.j += 1;
+        if self.j == self.b.len() {
+            self.j = 0;
+            self.i += 1;
+            self.done = self.i == self.a.len();
+        }
+
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        exact_hint(self.remaining, self.done)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binomial_exact_when_result_fits() {
+        assert_eq!(binomial(67, 33), Some(14_226_520_737_620_288_370));
+        assert_eq!(binomial(68, 34), None);
+        assert_eq!(binomial(5, 0), Some(1));
+        assert_eq!(binomial(5, 6), Some(0));
+        assert_eq!(binomial(usize::MAX, 1), Some(usize::MAX));
+    }
+
+    #[test]
+    fn size_hints_are_exact() {
+        let mut perms = permutations(&[1, 2, 3, 4]);
+        assert_eq!(perms.size_hint(), (24, Some(24)));
+        perms.next();
+        assert_eq!(perms.size_hint(), (23, Some(23)));
+        assert_eq!(perms.count(), 23);
+
+        let combos = combinations(&[0u8; 67], 33);
+        let count = 14_226_520_737_620_288_370;
+        assert_eq!(combos.size_hint(), (count, Some(count)));
+
+        let mut prod = product(&[0, 1, 2], 3);
+        prod.next();
+        assert_eq!(prod.size_hint(), (26, Some(26)));
+        assert_eq!(prod.count(), 26);
+
+        let mut pairs = cartesian_product(&[1, 2, 3], &['a', 'b']);
+        assert_eq!(pairs.size_hint(), (6, Some(6)));
+        pairs.next();
+        pairs.next();
+        assert_eq!(pairs.size_hint(), (4, Some(4)));
+        assert_eq!(pairs.count(), 4);
+        assert_eq!(
+            cartesian_product::<u8, u8>(&[1], &[]).size_hint(),
+            (0, Some(0))
+        );
+    }
+
+    #[test]
+    fn unknown_size_hint_has_zero_lower_bound() {
+        assert_eq!(permutations(&[0u8; 25]).size_hint(), (0, None));
+        assert_eq!(combinations(&[0u8; 68], 34).size_hint(), (0, None));
+        assert_eq!(product(&[0u8; 2], 64).size_hint(), (0, None));
+    }
+
+    #[test]
+    fn empty_inputs() {
+        assert_eq!(permutations::<u8>(&[]).collect::<Vec<_>>(), vec![vec![]]);
+        assert_eq!(combinations::<u8>(&[], 0).collect::<Vec<_>>(), vec![vec![]]);
+        assert_eq!(combinations::<u8>(&[], 1).size_hint(), (0, Some(0)));
+        assert_eq!(combinations::<u8>(&[], 1).count(), 0);
+        assert_eq!(product::<u8>(&[], 2).size_hint(), (0, Some(0)));
+        assert_eq!(product::<u8>(&[], 2).count(), 0);
+        assert_eq!(cartesian_product::<u8, u8>(&[], &[1]).count(), 0);
+    }
+
+    #[test]
+    fn k_zero_yields_one_empty_combination() {
+        let combos = combinations(&[1, 2, 3], 0);
+        assert_eq!(combos.size_hint(), (1, Some(1)));
+        assert_eq!(combos.collect::<Vec<_>>(), vec![Vec::<i32>::new()]);
+    }
+
+    #[test]
+    fn repeat_zero_yields_one_empty_product() {
+        let prod = product(&[1, 2, 3], 0);
+        assert_eq!(prod.size_hint(), (1, Some(1)));
+        assert_eq!(prod.collect::<Vec<_>>(), vec![Vec::<i32>::new()]);
+        assert_eq!(product::<u8>(&[], 0).count(), 1);
+    }
+
+    #[test]
+    fn combinations_larger_than_input() {
+        assert_eq!(combinations(&[1, 2], 3).count(), 0);
+    }
+
+    #[test]
+    fn permutations_are_lexicographic() {
+        let last = permutations(&[1, 2, 3, 4]).last();
+        assert_eq!(last, Some(vec![4, 3, 2, 1]));
+    }
+
+    #[test]
+    fn cartesian_product_order() {
+        let pairs: Vec<_> = cartesian_product(&[1, 2], &['a', 'b']).collect();
+        assert_eq!(pairs, vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+    }
+}
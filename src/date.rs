@@ -0,0 +1,370 @@
+//! Validated event year and puzzle day values.
+//!
+//! Both are checked on construction, so a malformed `--year 21` or `day 26`
+//! is rejected before it is used to build a URL or a cache path.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::wait::WaitTime;
+
+/// The first year Advent of Code ran.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Puzzles unlock at midnight US Eastern time, which AoC treats as UTC-5.
+const UNLOCK_OFFSET_SECS: i64 = 5 * 60 * 60;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateError {
+    /// The text was not a number.
+    NotANumber(String),
+    /// The year is before 2015 or its event has not started yet.
+    YearOutOfRange(u32),
+    /// The day is not in `1..=25`.
+    DayOutOfRange(u32),
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateError::NotANumber(text) => write!(f, "{text:?} is not a number"),
+            DateError::YearOutOfRange(year) => write!(
+                f,
+                "year {year} is not an Advent of Code event ({FIRST_YEAR}..={})",
+                Year::latest()
+            ),
+            DateError::DayOutOfRange(day) => write!(f, "day {day} is not in 1..=25"),
+        }
+    }
+}
+
+impl Error for DateError {}
+
+/// An Advent of Code event year, from 2015 up to the latest event that has
+/// started.
+///
+/// ```
+/// use aoc_rs::date::Year;
+///
+/// let year: Year = "2021".parse().unwrap();
+/// assert_eq!(year.get(), 2021);
+/// assert!("2014".parse::<Year>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Year(u16);
+
+impl Year {
+    pub fn new(year: u16) -> Result<Self, DateError> {
+        Year::try_from(u32::from(year))
+    }
+
+    pub fn get(self) -> u16 {
+        self.0
+    }
+
+    /// The most recent event year whose first puzzle has unlocked.
+    pub fn latest() -> Year {
+        Year::latest_at(now_secs())
+    }
+
+    /// Same as [`Year::latest`], at the given Unix timestamp.
+    fn latest_at(unix_secs: i64) -> Year {
+        let days = (unix_secs - UNLOCK_OFFSET_SECS).div_euclid(24 * 60 * 60);
+        let (year, month, _) = civil_from_days(days);
+        let year = if month == 12 { year } else { year - 1 };
+        Year(year.clamp(i64::from(FIRST_YEAR), i64::from(u16::MAX)) as u16)
+    }
+}
+
+impl TryFrom<u32> for Year {
+    type Error = DateError;
+
+    fn try_from(year: u32) -> Result<Self, DateError> {
+        if year < u32::from(FIRST_YEAR) || year > u32::from(Year::latest().0) {
+            return Err(DateError::YearOutOfRange(year));
+        }
+        Ok(Year(year as u16))
+    }
+}
+
+impl FromStr for Year {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> Result<Self, DateError> {
+        Year::try_from(parse_number(s)?)
+    }
+}
+
+impl fmt::Display for Year {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl From<Year> for u16 {
+    fn from(year: Year) -> u16 {
+        year.0
+    }
+}
+
+/// A puzzle day, `1..=25`.
+///
+/// `Display` forwards formatting flags, so `{:02}` gives `"07"`.
+///
+/// ```
+/// use aoc_rs::date::Day;
+///
+/// let day = Day::new(7).unwrap();
+/// assert_eq!(format!("day{day:02}"), "day07");
+/// assert!(Day::new(26).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Day(u8);
+
+impl Day {
+    pub fn new(day: u8) -> Result<Self, DateError> {
+        Day::try_from(u32::from(day))
+    }
+
+    pub fn get(self) -> u8 {
+        self.0
+    }
+
+    /// Every day of an event, in order.
+    pub fn all() -> impl Iterator<Item = Day> {
+        (1..=25).map(Day)
+    }
+}
+
+impl TryFrom<u32> for Day {
+    type Error = DateError;
+
+    fn try_from(day: u32) -> Result<Self, DateError> {
+        if !(1..=25).contains(&day) {
+            return Err(DateError::DayOutOfRange(day));
+        }
+        Ok(Day(day as u8))
+    }
+}
+
+impl FromStr for Day {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> Result<Self, DateError> {
+        Day::try_from(parse_number(s)?)
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl From<Day> for u8 {
+    fn from(day: Day) -> u8 {
+        day.0
+    }
+}
+
+/// The moment the puzzle for `day` of `year` unlocks: midnight UTC-5 on
+/// that day of December.
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use aoc_rs::date::{unlock_time, Day, Year};
+///
+/// let time = unlock_time(Year::new(2021).unwrap(), Day::new(1).unwrap());
+/// assert_eq!(time, UNIX_EPOCH + Duration::from_secs(1_638_334_800));
+/// ```
+pub fn unlock_time(year: Year, day: Day) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(unlock_secs(year, day) as u64)
+}
+
+/// Whether the puzzle for `day` of `year` can be fetched yet.
+pub fn is_unlocked(year: Year, day: Day) -> bool {
+    is_unlocked_at(year, day, now_secs())
+}
+
+/// The event year and day whose puzzle is the most recent to unlock, or
+/// `None` outside December 1–25 (UTC-5).
+pub fn today() -> Option<(Year, Day)> {
+    today_at(now_secs())
+}
+
+fn today_at(unix_secs: i64) -> Option<(Year, Day)> {
+    let days = (unix_secs - UNLOCK_OFFSET_SECS).div_euclid(24 * 60 * 60);
+    let (year, month, day) = civil_from_days(days);
+    if month != 12 || day > 25 || year < i64::from(FIRST_YEAR) {
+        return None;
+    }
+    Some((Year(u16::try_from(year).ok()?), Day(day as u8)))
+}
+
+/// How long until the puzzle for `day` of `year` unlocks, or zero if it
+/// already has. Sleeping for this long and then fetching gets the input as
+/// soon as it is out.
+pub fn until_unlock(year: Year, day: Day) -> WaitTime {
+    until_unlock_at(year, day, now_secs())
+}
+
+fn until_unlock_at(year: Year, day: Day, unix_secs: i64) -> WaitTime {
+    let secs = (unlock_secs(year, day) - unix_secs).max(0);
+    WaitTime::from_secs(secs as u64)
+}
+
+fn unlock_secs(year: Year, day: Day) -> i64 {
+    let days = days_from_civil(i64::from(year.0), 12, u32::from(day.0));
+    days * 24 * 60 * 60 + UNLOCK_OFFSET_SECS
+}
+
+fn is_unlocked_at(year: Year, day: Day, unix_secs: i64) -> bool {
+    unix_secs >= unlock_secs(year, day)
+}
+
+fn now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+fn parse_number(s: &str) -> Result<u32, DateError> {
+    s.trim()
+        .parse()
+        .map_err(|_| DateError::NotANumber(s.to_string()))
+}
+
+/// Civil year, month and day of a day count since 1970-01-01, using Howard
+/// Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Day count since 1970-01-01 of a civil date, using Howard Hinnant's
+/// `days_from_civil` algorithm.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let (month, day) = (i64::from(month), i64::from(day));
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2021-12-01T05:00:00Z, the moment the 2021 event started.
+    const EVENT_2021_START: i64 = 1_638_334_800;
+
+    #[test]
+    fn civil_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(EVENT_2021_START / 86_400), (2021, 12, 1));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+    }
+
+    #[test]
+    fn days_from_civil_inverts_year_month() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(2000, 2, 29), 11_016);
+        assert_eq!(days_from_civil(2021, 12, 1), EVENT_2021_START / 86_400);
+    }
+
+    #[test]
+    fn puzzle_unlocks_at_midnight_eastern() {
+        let (year, day) = (Year(2021), Day(1));
+        assert_eq!(unlock_secs(year, day), EVENT_2021_START);
+        assert!(!is_unlocked_at(year, day, EVENT_2021_START - 1));
+        assert!(is_unlocked_at(year, day, EVENT_2021_START));
+        assert_eq!(unlock_secs(year, Day(25)), EVENT_2021_START + 24 * 86_400);
+        assert_eq!(
+            unlock_time(year, Day(2)),
+            UNIX_EPOCH + Duration::from_secs(EVENT_2021_START as u64 + 86_400)
+        );
+    }
+
+    #[test]
+    fn wait_until_unlock() {
+        let (year, day) = (Year(2021), Day(2));
+        let unlock = EVENT_2021_START + 86_400;
+        assert_eq!(
+            until_unlock_at(year, day, unlock - 65),
+            WaitTime::from_secs(65)
+        );
+        assert_eq!(until_unlock_at(year, day, unlock), WaitTime::ZERO);
+        assert_eq!(until_unlock_at(year, day, unlock + 10), WaitTime::ZERO);
+    }
+
+    #[test]
+    fn today_is_only_set_during_the_event() {
+        let day = |d: u8| Some((Year(2021), Day(d)));
+        assert_eq!(today_at(EVENT_2021_START - 1), None);
+        assert_eq!(today_at(EVENT_2021_START), day(1));
+        assert_eq!(today_at(EVENT_2021_START + 86_399), day(1));
+        assert_eq!(today_at(EVENT_2021_START + 86_400), day(2));
+        assert_eq!(today_at(EVENT_2021_START + 25 * 86_400 - 1), day(25));
+        assert_eq!(today_at(EVENT_2021_START + 25 * 86_400), None);
+        assert_eq!(today_at(0), None);
+    }
+
+    #[test]
+    fn latest_year_switches_at_unlock() {
+        assert_eq!(Year::latest_at(EVENT_2021_START - 1).get(), 2020);
+        assert_eq!(Year::latest_at(EVENT_2021_START).get(), 2021);
+        assert_eq!(Year::latest_at(EVENT_2021_START + 40 * 86_400).get(), 2021);
+        assert_eq!(Year::latest_at(0).get(), FIRST_YEAR);
+    }
+
+    #[test]
+    fn year_bounds() {
+        assert_eq!(Year::new(2015).map(Year::get), Ok(2015));
+        assert_eq!(Year::new(2014), Err(DateError::YearOutOfRange(2014)));
+        let next = u32::from(Year::latest().get()) + 1;
+        assert_eq!(Year::try_from(next), Err(DateError::YearOutOfRange(next)));
+    }
+
+    #[test]
+    fn day_bounds() {
+        assert_eq!(Day::new(1).map(Day::get), Ok(1));
+        assert_eq!(Day::new(25).map(Day::get), Ok(25));
+        assert_eq!(Day::new(0), Err(DateError::DayOutOfRange(0)));
+        assert_eq!(Day::try_from(256), Err(DateError::DayOutOfRange(256)));
+        assert_eq!(Day::all().count(), 25);
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(" 07\n".parse::<Day>(), Ok(Day(7)));
+        assert_eq!(
+            "seven".parse::<Day>(),
+            Err(DateError::NotANumber("seven".to_string()))
+        );
+        assert_eq!(
+            "-1".parse::<Year>(),
+            Err(DateError::NotANumber("-1".to_string()))
+        );
+        assert_eq!("2016".parse::<Year>().map(u16::from), Ok(2016));
+    }
+
+    #[test]
+    fn display_forwards_flags() {
+        assert_eq!(format!("{}/{:02}", Year(2019), Day(3)), "2019/03");
+    }
+}
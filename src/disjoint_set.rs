@@ -0,0 +1,161 @@
+//! Union-find over the indices `0..n`, for connected-components puzzles.
+
+/// A disjoint set forest with path compression and union by rank.
+///
+/// ```
+/// use aoc_rs::disjoint_set::DisjointSet;
+///
+/// let mut set = DisjointSet::new(5);
+/// set.union(0, 1);
+/// set.union(3, 4);
+/// set.union(1, 4);
+///
+/// assert!(set.connected(0, 3));
+/// assert_eq!(set.component_size(4), 4);
+/// assert_eq!(set.components(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    /// `len` singleton sets, one per index.
+    pub fn new(len: usize) -> Self {
+        DisjointSet {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// Number of elements, not components.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Adds a new singleton set and returns its index.
+    pub fn push(&mut self) -> usize {
+        let index = self.parents.len();
+        self.parents.push(index);
+        self.ranks.push(0);
+        self.sizes.push(1);
+        self.components += 1;
+        index
+    }
+
+    /// The representative of the set containing `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut node = x;
+        while self.parents[node] != root {
+            node = std::mem::replace(&mut self.parents[node], root);
+        }
+
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`. Returns `false` if they were
+    /// already the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.ranks[a] < self.ranks[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        if self.ranks[a] == self.ranks[b] {
+            self.ranks[a] += 1;
+        }
+
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the set containing `x`.
+    pub fn component_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.sizes[root]
+    }
+
+    /// Number of disjoint sets.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Sizes of every set, largest first.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = (0..self.len())
+            .filter(|&x| self.parents[x] == x)
+            .map(|root| self.sizes[root])
+            .collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_of_joined_sets_is_a_no_op() {
+        let mut set = DisjointSet::new(3);
+        assert!(set.union(0, 1));
+        assert_eq!(set.components(), 2);
+
+        assert!(!set.union(1, 0));
+        assert!(!set.union(0, 0));
+        assert_eq!(set.components(), 2);
+        assert_eq!(set.component_size(0), 2);
+    }
+
+    #[test]
+    fn push_adds_a_singleton() {
+        let mut set = DisjointSet::new(2);
+        set.union(0, 1);
+
+        let new = set.push();
+        assert_eq!(new, 2);
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.components(), 2);
+        assert!(!set.connected(0, new));
+
+        set.union(new, 1);
+        assert_eq!(set.components(), 1);
+        assert_eq!(set.component_size(new), 3);
+    }
+
+    #[test]
+    fn component_sizes_largest_first() {
+        let mut set = DisjointSet::new(7);
+        set.union(0, 1);
+        set.union(2, 3);
+        set.union(1, 3);
+        set.union(4, 5);
+
+        assert_eq!(set.component_sizes(), vec![4, 2, 1]);
+        assert_eq!(set.component_sizes().iter().sum::<usize>(), set.len());
+        assert!(DisjointSet::new(0).component_sizes().is_empty());
+    }
+}
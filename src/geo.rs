@@ -0,0 +1,379 @@
+//! Points and directions on a 2D grid.
+//!
+//! Coordinates follow the layout of puzzle inputs: `x` grows to the right and
+//! `y` grows downwards, so row `0` is the first line of the input.
+//!
+//! [`Grid`] stores the parsed input and iterates neighbours within its
+//! bounds. For grids that are not stored, [`Point::neighbors4_within`] and
+//! [`Point::neighbors8_within`] do the same against a width and height.
+
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Moves one cell in the given direction.
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.delta()
+    }
+
+    /// Up, right, down and left neighbours, in that order.
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// All eight surrounding cells, clockwise from the top-left one.
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        const OFFSETS: [(i64, i64); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+        ];
+        OFFSETS
+            .into_iter()
+            .map(move |(dx, dy)| self + Point::new(dx, dy))
+    }
+
+    /// Whether the point lies inside a `width` x `height` grid anchored at
+    /// the origin.
+    pub fn in_bounds(self, width: usize, height: usize) -> bool {
+        usize::try_from(self.x).is_ok_and(|x| x < width)
+            && usize::try_from(self.y).is_ok_and(|y| y < height)
+    }
+
+    /// [`neighbors4`](Point::neighbors4) restricted to a `width` x `height`
+    /// grid.
+    pub fn neighbors4_within(self, width: usize, height: usize) -> impl Iterator<Item = Point> {
+        self.neighbors4()
+            .filter(move |p| p.in_bounds(width, height))
+    }
+
+    /// [`neighbors8`](Point::neighbors8) restricted to a `width` x `height`
+    /// grid.
+    pub fn neighbors8_within(self, width: usize, height: usize) -> impl Iterator<Item = Point> {
+        self.neighbors8()
+            .filter(move |p| p.in_bounds(width, height))
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting from `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    /// Parses the arrow and letter forms used by inputs: `^>v<`, `URDL` and
+    /// `NESW`.
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' | 'U' | 'N' => Some(Direction::Up),
+            '>' | 'R' | 'E' => Some(Direction::Right),
+            'v' | 'D' | 'S' => Some(Direction::Down),
+            '<' | 'L' | 'W' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// ```
+/// use aoc_rs::geo::{Grid, Point};
+///
+/// let grid = Grid::parse("#..\n.#.", |c| c == '#');
+/// assert_eq!((grid.width(), grid.height()), (3, 2));
+/// assert!(grid[Point::new(1, 1)]);
+/// assert_eq!(grid.neighbors4(Point::new(0, 0)).count(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from the lines of `input`, mapping every character
+    /// with `f`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lines are not all the same length.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for line in input.lines() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let len = cells.len() - before;
+            if height == 0 {
+                width = len;
+            }
+            assert_eq!(len, width, "line {height} has a different length");
+            height += 1;
+        }
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// A `width` x `height` grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.in_bounds(self.width, self.height)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as i64;
+        (0..self.height as i64).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// The first point, row by row, whose cell matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.points().find(|&p| predicate(&self[p]))
+    }
+
+    /// [`Point::neighbors4`] restricted to the grid.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> {
+        point.neighbors4_within(self.width, self.height)
+    }
+
+    /// [`Point::neighbors8`] restricted to the grid.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> {
+        point.neighbors8_within(self.width, self.height)
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("point {point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("point {point:?} is outside the grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors8_are_clockwise_from_top_left() {
+        let around: Vec<_> = Point::new(1, 1).neighbors8().collect();
+        assert_eq!(
+            around,
+            [
+                (0, 0),
+                (1, 0),
+                (2, 0),
+                (2, 1),
+                (2, 2),
+                (1, 2),
+                (0, 2),
+                (0, 1)
+            ]
+            .map(Point::from)
+        );
+    }
+
+    #[test]
+    fn in_bounds_rejects_negative_coordinates() {
+        assert!(Point::new(0, 0).in_bounds(1, 1));
+        assert!(!Point::new(-1, 0).in_bounds(5, 5));
+        assert!(!Point::new(0, -1).in_bounds(5, 5));
+        assert!(!Point::new(5, 0).in_bounds(5, 5));
+    }
+
+    #[test]
+    fn neighbors_within_clip_at_corners() {
+        assert_eq!(Point::ORIGIN.neighbors4_within(3, 3).count(), 2);
+        assert_eq!(Point::ORIGIN.neighbors8_within(3, 3).count(), 3);
+        assert_eq!(Point::new(1, 1).neighbors8_within(3, 3).count(), 8);
+    }
+
+    #[test]
+    fn turns_round_trip() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_right().turn_right(), d.reverse());
+            assert_eq!(d.reverse().reverse(), d);
+            assert_eq!(Point::ORIGIN.step(d).step(d.reverse()), Point::ORIGIN);
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    }
+
+    #[test]
+    fn from_char_accepts_arrows_and_letters() {
+        assert_eq!(Direction::from_char('^'), Some(Direction::Up));
+        assert_eq!(Direction::from_char('R'), Some(Direction::Right));
+        assert_eq!(Direction::from_char('S'), Some(Direction::Down));
+        assert_eq!(Direction::from_char('<'), Some(Direction::Left));
+        assert_eq!(Direction::from_char('x'), None);
+    }
+
+    #[test]
+    fn manhattan_is_symmetric() {
+        let (a, b) = (Point::new(-3, 4), Point::new(2, -1));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(b.manhattan(a), 10);
+    }
+
+    #[test]
+    fn grid_lookup_and_search() {
+        let mut grid = Grid::parse("ab\ncS", |c| c);
+        assert_eq!(grid.position(|&c| c == 'S'), Some(Point::new(1, 1)));
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        grid[Point::new(0, 1)] = 'z';
+        assert_eq!(grid[Point::new(0, 1)], 'z');
+        assert_eq!(grid.points().count(), 4);
+        assert_eq!(Grid::filled(4, 2, 0u8).points().count(), 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "different length")]
+    fn grid_rejects_ragged_lines() {
+        Grid::parse("ab\nc", |c| c);
+    }
+}
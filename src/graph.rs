@@ -0,0 +1,293 @@
+//! Generic shortest path searches over user-provided successor functions.
+//!
+//! Nodes are anything `Clone + Eq + Hash`, so grid points, `(Point, Direction)`
+//! states or packed integers all work without building an explicit graph.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Breadth-first search from `start` to the first node accepted by
+/// `is_goal`, returning the path including both ends.
+///
+/// ```
+/// use aoc_rs::graph::bfs;
+///
+/// let path = bfs(1u32, |&n| [n + 1, n * 2], |&n| n == 10).unwrap();
+/// assert_eq!(path, vec![1, 2, 4, 5, 10]);
+/// ```
+pub fn bfs<N, S, I, G>(start: N, mut successors: S, mut is_goal: G) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(reconstruct(&parents, node));
+        }
+
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Number of steps from `start` to every reachable node.
+pub fn bfs_distances<N, S, I>(start: N, mut successors: S) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+/// Dijkstra's algorithm from `start` to the first node accepted by
+/// `is_goal`, returning the cheapest path and its cost.
+///
+/// Successors yield `(node, edge_cost)` pairs; costs must not be negative.
+pub fn dijkstra<N, C, S, I, G>(start: N, successors: S, is_goal: G) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Cheapest cost from `start` to every reachable node.
+pub fn dijkstra_all<N, C, S, I>(start: N, mut successors: S) -> HashMap<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new(start);
+
+    while let Some((index, cost)) = search.pop() {
+        let node = search.nodes[index].clone();
+        for (next, edge) in successors(&node) {
+            search.relax(index, next, cost + edge, cost + edge);
+        }
+    }
+
+    search.nodes.into_iter().zip(search.costs).collect()
+}
+
+/// A* search from `start` to the first node accepted by `is_goal`.
+///
+/// `heuristic` must never overestimate the remaining cost, otherwise the
+/// returned path is not guaranteed to be the cheapest one.
+///
+/// ```
+/// use aoc_rs::graph::astar;
+///
+/// let goal = (3i32, 2i32);
+/// let (path, cost) = astar(
+///     (0i32, 0i32),
+///     |&(x, y)| [((x + 1, y), 1u32), ((x, y + 1), 1u32)],
+///     |&(x, y)| (goal.0 - x).unsigned_abs() + (goal.1 - y).unsigned_abs(),
+///     |&node| node == goal,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(cost, 5);
+/// assert_eq!(path.len(), 6);
+/// ```
+pub fn astar<N, C, S, I, H, G>(
+    start: N,
+    mut successors: S,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut search = Search::new(start);
+
+    while let Some((index, cost)) = search.pop() {
+        let node = search.nodes[index].clone();
+        if is_goal(&node) {
+            return Some((search.path_to(index), cost));
+        }
+
+        for (next, edge) in successors(&node) {
+            let estimate = cost + edge + heuristic(&next);
+            search.relax(index, next, cost + edge, estimate);
+        }
+    }
+
+    None
+}
+
+/// Bookkeeping shared by Dijkstra and A*. Nodes are interned into a `Vec` so
+/// the heap only has to order indices, not the nodes themselves.
+struct Search<N, C> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    parents: Vec<usize>,
+    costs: Vec<C>,
+    heap: BinaryHeap<Reverse<(C, C, usize)>>,
+}
+
+impl<N, C> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default,
+{
+    fn new(start: N) -> Self {
+        Search {
+            nodes: vec![start.clone()],
+            indices: HashMap::from([(start, 0)]),
+            parents: vec![0],
+            costs: vec![C::default()],
+            heap: BinaryHeap::from([Reverse((C::default(), C::default(), 0))]),
+        }
+    }
+
+    /// Pops the next node to expand, skipping stale heap entries.
+    fn pop(&mut self) -> Option<(usize, C)> {
+        while let Some(Reverse((_, cost, index))) = self.heap.pop() {
+            if cost == self.costs[index] {
+                return Some((index, cost));
+            }
+        }
+        None
+    }
+
+    fn relax(&mut self, parent: usize, node: N, cost: C, estimate: C) {
+        let index = match self.indices.entry(node) {
+            Entry::Occupied(entry) => {
+                let index = *entry.get();
+                if cost >= self.costs[index] {
+                    return;
+                }
+                self.costs[index] = cost;
+                self.parents[index] = parent;
+                index
+            }
+            Entry::Vacant(entry) => {
+                let index = self.nodes.len();
+                self.nodes.push(entry.key().clone());
+                entry.insert(index);
+                self.costs.push(cost);
+                self.parents.push(parent);
+                index
+            }
+        };
+
+        self.heap.push(Reverse((estimate, cost, index)));
+    }
+
+    fn path_to(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[index].clone()];
+        while index != 0 {
+            index = self.parents[index];
+            path.push(self.nodes[index].clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+fn reconstruct<N: Clone + Eq + Hash>(parents: &HashMap<N, Option<N>>, goal: N) -> Vec<N> {
+    let mut path = vec![goal];
+    while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 2 is discovered first at cost 10, then relaxed to 3 via 1 and 3.
+    fn edges(node: &u8) -> Vec<(u8, u32)> {
+        match node {
+            0 => vec![(2, 10), (1, 1)],
+            1 => vec![(3, 1)],
+            3 => vec![(2, 1)],
+            2 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_takes_the_relaxed_path() {
+        assert_eq!(
+            dijkstra(0, edges, |&n| n == 4),
+            Some((vec![0, 1, 3, 2, 4], 4))
+        );
+    }
+
+    #[test]
+    fn dijkstra_unreachable_goal() {
+        assert_eq!(dijkstra(0, edges, |&n| n == 9), None);
+    }
+
+    #[test]
+    fn dijkstra_start_is_goal() {
+        assert_eq!(dijkstra(3, edges, |&n| n == 3), Some((vec![3], 0)));
+    }
+
+    #[test]
+    fn dijkstra_all_keeps_cheapest_costs() {
+        let costs = dijkstra_all(0, edges);
+        assert_eq!(costs.len(), 5);
+        assert_eq!(costs[&2], 3);
+        assert_eq!(costs[&4], 4);
+    }
+
+    #[test]
+    fn astar_unreachable_goal() {
+        assert_eq!(astar(0, edges, |_| 0, |&n| n == 9), None);
+    }
+
+    #[test]
+    fn bfs_unreachable_goal() {
+        assert_eq!(bfs(0u8, |&n| (n < 3).then_some(n + 1), |&n| n == 7), None);
+    }
+
+    #[test]
+    fn bfs_distances_counts_steps() {
+        let distances = bfs_distances(0u8, |&n| edges(&n).into_iter().map(|(next, _)| next));
+        assert_eq!(distances[&2], 1);
+        assert_eq!(distances[&4], 2);
+        assert_eq!(distances[&3], 2);
+        assert_eq!(distances.len(), 5);
+    }
+}
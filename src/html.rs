@@ -0,0 +1,73 @@
+//! Just enough HTML handling for the site's pages, which are small and
+//! regular: tag stripping and slicing between known markers, no parser.
+
+/// The text of `html` with tags removed and the entities the site uses
+/// decoded. Whitespace is kept, so `<pre>` blocks keep their lines.
+pub(crate) fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Like [`strip_tags`], with each run of whitespace collapsed to one space,
+/// so phrases match however the page is wrapped.
+pub(crate) fn plain_text(html: &str) -> String {
+    strip_tags(html)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The pieces of `html` between each `open` marker and the first `close`
+/// marker after it, in order.
+pub(crate) fn sections<'a>(
+    html: &'a str,
+    open: &'a str,
+    close: &'a str,
+) -> impl Iterator<Item = &'a str> + 'a {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let start = rest.find(open)? + open.len();
+        let len = rest[start..].find(close)?;
+        let section = &rest[start..start + len];
+        rest = &rest[start + len + close.len()..];
+        Some(section)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_tags_and_entities() {
+        assert_eq!(
+            strip_tags("<p>a &lt;<em>b</em>&gt; &amp;lt;</p>\n<p>c</p>"),
+            "a <b> &lt;\nc"
+        );
+        assert_eq!(plain_text("<p>That&#39;s\n  <em>it</em></p>"), "That's it");
+    }
+
+    #[test]
+    fn sections_between_markers() {
+        let html = "<code>1</code> x <code>2</code> <code>unclosed";
+        assert_eq!(
+            sections(html, "<code>", "</code>").collect::<Vec<_>>(),
+            vec!["1", "2"]
+        );
+        assert_eq!(sections("", "<code>", "</code>").count(), 0);
+    }
+}
@@ -0,0 +1,114 @@
+//! Sanity checks for puzzle input text, such as a file read back from a
+//! cache.
+//!
+//! When a download fails, the site still answers with a short message or an
+//! HTML page. If that body was saved as the input, every solver gets garbage.
+//! [`validate`] recognises those bodies so the caller can download again.
+
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputError {
+    /// The input is empty or only whitespace.
+    Empty,
+    /// The site's reply to a request without a valid session cookie.
+    NotLoggedIn,
+    /// The site's reply to a request made before the puzzle unlocked.
+    NotUnlocked,
+    /// An HTML page, such as a 404 or 500 error page.
+    Html,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            InputError::Empty => "input is empty",
+            InputError::NotLoggedIn => "input is the site's log-in message",
+            InputError::NotUnlocked => "input is the site's not-unlocked-yet message",
+            InputError::Html => "input is an HTML page",
+        })
+    }
+}
+
+impl Error for InputError {}
+
+/// Checks that `input` looks like puzzle input rather than an error reply.
+///
+/// ```
+/// use aoc_rs::input::{validate, InputError};
+///
+/// assert_eq!(validate("199\n200\n208\n"), Ok(()));
+/// assert_eq!(validate("<!DOCTYPE html>\n<html>"), Err(InputError::Html));
+/// ```
+pub fn validate(input: &str) -> Result<(), InputError> {
+    let text = input.trim_start();
+    if text.trim_end().is_empty() {
+        return Err(InputError::Empty);
+    }
+    if text.starts_with("Puzzle inputs differ by user") {
+        return Err(InputError::NotLoggedIn);
+    }
+    if text.starts_with("Please don't repeatedly request this endpoint before it unlocks") {
+        return Err(InputError::NotUnlocked);
+    }
+
+    let head: String = text.chars().take(16).collect();
+    let head = head.to_ascii_lowercase();
+    if ["<!doctype html", "<html", "<head", "<body"]
+        .iter()
+        .any(|tag| head.starts_with(tag))
+    {
+        return Err(InputError::Html);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_puzzle_inputs() {
+        assert_eq!(validate("1721\n979\n366\n"), Ok(()));
+        assert_eq!(validate("#.##..##.\n..#.##.#.\n"), Ok(()));
+        // Some inputs legitimately contain angle brackets.
+        assert_eq!(validate("<x=-1, y=0, z=2>\n<x=2, y=-10, z=-7>\n"), Ok(()));
+        assert_eq!(validate("{([(<{}[<>[]}>{[]{[(<()>\n"), Ok(()));
+    }
+
+    #[test]
+    fn rejects_empty() {
+        assert_eq!(validate(""), Err(InputError::Empty));
+        assert_eq!(validate(" \n\n"), Err(InputError::Empty));
+    }
+
+    #[test]
+    fn rejects_site_messages() {
+        assert_eq!(
+            validate("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            Err(InputError::NotLoggedIn)
+        );
+        assert_eq!(
+            validate(
+                "Please don't repeatedly request this endpoint before it unlocks! The calendar \
+                 countdown is synchronized with the server time; the link will be enabled on \
+                 the calendar the instant this puzzle becomes available.\n"
+            ),
+            Err(InputError::NotUnlocked)
+        );
+    }
+
+    #[test]
+    fn rejects_html_pages() {
+        assert_eq!(
+            validate("\n<!DOCTYPE html>\n<html lang=\"en-us\">"),
+            Err(InputError::Html)
+        );
+        assert_eq!(
+            validate("<HTML><BODY>500 Internal Server Error</BODY></HTML>"),
+            Err(InputError::Html)
+        );
+        assert_eq!(InputError::Html.to_string(), "input is an HTML page");
+    }
+}
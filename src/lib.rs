@@ -0,0 +1,17 @@
+pub mod answer;
+pub mod bench;
+pub mod combinatorics;
+pub mod date;
+pub mod disjoint_set;
+pub mod geo;
+pub mod graph;
+mod html;
+pub mod input;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod puzzle;
+pub mod ranges;
+pub mod session;
+pub mod solution;
+pub mod wait;
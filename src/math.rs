@@ -0,0 +1,255 @@
+//! Number theory helpers for the "when do all the cycles line up" puzzles.
+//!
+//! Intermediate products are computed in 128-bit arithmetic, so none of these
+//! overflow for 64-bit inputs unless the final result itself does not fit.
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, or `None` if it does not fit in a `u64`.
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple.
+///
+/// # Panics
+///
+/// Panics if the result does not fit in a `u64`; use [`checked_lcm`] to
+/// handle that case.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    checked_lcm(a, b).expect("lcm overflowed u64")
+}
+
+/// Least common multiple of every value, `1` for an empty iterator.
+///
+/// ```
+/// use aoc_rs::math::lcm_all;
+///
+/// assert_eq!(lcm_all([4, 6, 10]), Some(60));
+/// ```
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, checked_lcm)
+}
+
+/// Computes `base^exp mod modulus` by repeated squaring.
+///
+/// # Panics
+///
+/// Panics if `modulus` is zero.
+///
+/// ```
+/// use aoc_rs::math::mod_pow;
+///
+/// assert_eq!(mod_pow(7, 20201227, 20201227), 7);
+/// ```
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    assert!(modulus != 0, "modulus must be non-zero");
+
+    let modulus = u128::from(modulus);
+    let mut base = u128::from(base) % modulus;
+    let mut result = 1 % modulus;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+
+    result as u64
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`.
+///
+/// Returns `None` only when the gcd is `2^63`, which happens for
+/// `(i64::MIN, 0)`, `(0, i64::MIN)` and `(i64::MIN, i64::MIN)` and does not
+/// fit in an `i64`.
+pub fn extended_gcd(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+    let (g, x, y) = extended_gcd_wide(i128::from(a), i128::from(b));
+    Some((g.try_into().ok()?, x.try_into().ok()?, y.try_into().ok()?))
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    let (mut old_t, mut t) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+        (old_t, t) = (t, old_t - q * t);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+/// Modular multiplicative inverse of `a` modulo `modulus`, if it exists.
+///
+/// ```
+/// use aoc_rs::math::mod_inv;
+///
+/// assert_eq!(mod_inv(3, 11), Some(4));
+/// assert_eq!(mod_inv(2, 4), None);
+/// ```
+pub fn mod_inv(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+    mod_inv_wide(i128::from(a), i128::from(modulus)).map(|inv| inv as i64)
+}
+
+fn mod_inv_wide(a: i128, modulus: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd_wide(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)`.
+///
+/// Moduli do not have to be pairwise coprime. Returns the smallest
+/// non-negative solution together with the combined modulus, or `None` if the
+/// system has no solution or the combined modulus does not fit in an `i64`.
+///
+/// ```
+/// use aoc_rs::math::crt;
+///
+/// // x ≡ 2 (mod 3), x ≡ 3 (mod 5), x ≡ 2 (mod 7)
+/// assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+/// ```
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut residue: i128 = 0;
+    let mut modulus: i128 = 1;
+
+    for &(r, m) in congruences {
+        if m <= 0 {
+            return None;
+        }
+        let (r, m) = (i128::from(r).rem_euclid(i128::from(m)), i128::from(m));
+
+        let (g, _, _) = extended_gcd_wide(modulus, m);
+        let diff = r - residue;
+        if diff % g != 0 {
+            return None;
+        }
+
+        let step = m / g;
+        let k = if step == 1 {
+            0
+        } else {
+            (diff / g).rem_euclid(step) * mod_inv_wide(modulus / g, step)? % step
+        };
+
+        let combined = modulus.checked_mul(step)?;
+        if combined > i128::from(i64::MAX) {
+            return None;
+        }
+
+        residue = (residue + modulus * k).rem_euclid(combined);
+        modulus = combined;
+    }
+
+    Some((residue as i64, modulus as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_lcm_overflow() {
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(checked_lcm(u64::MAX, 1), Some(u64::MAX));
+        assert_eq!(checked_lcm(0, 5), Some(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "lcm overflowed")]
+    fn lcm_panics_on_overflow() {
+        lcm(u64::MAX, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be non-zero")]
+    fn mod_pow_panics_on_zero_modulus() {
+        mod_pow(2, 3, 0);
+    }
+
+    #[test]
+    fn lcm_all_overflow_and_empty() {
+        assert_eq!(lcm_all([1 << 40, 3 << 40, 5 << 40]), Some(15 << 40));
+        assert_eq!(lcm_all([u64::MAX, u64::MAX - 1]), None);
+        assert_eq!(lcm_all([]), Some(1));
+    }
+
+    #[test]
+    fn mod_pow_large_modulus() {
+        let m = u64::MAX - 58; // largest 64-bit prime
+        assert_eq!(mod_pow(m - 1, 2, m), 1);
+        assert_eq!(mod_pow(2, m - 1, m), 1);
+        assert_eq!(mod_pow(u64::MAX, 1, u64::MAX), 0);
+        assert_eq!(mod_pow(3, 0, 1), 0);
+    }
+
+    #[test]
+    fn extended_gcd_bezout() {
+        let (g, x, y) = extended_gcd(240, 46).unwrap();
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, g);
+        assert_eq!(extended_gcd(-4, 6).unwrap().0, 2);
+    }
+
+    #[test]
+    fn extended_gcd_out_of_range() {
+        assert_eq!(extended_gcd(i64::MIN, 0), None);
+        assert_eq!(extended_gcd(i64::MIN, i64::MIN), None);
+        assert_eq!(extended_gcd(i64::MIN, 3).map(|(g, _, _)| g), Some(1));
+    }
+
+    #[test]
+    fn mod_inv_negative_input() {
+        assert_eq!(mod_inv(-3, 11), Some(7));
+        assert_eq!(mod_inv(-1, 5), Some(4));
+        assert_eq!(mod_inv(3, 0), None);
+        assert_eq!(mod_inv(3, -7), None);
+    }
+
+    #[test]
+    fn crt_empty_is_trivial() {
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn crt_non_coprime_moduli() {
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+    }
+
+    #[test]
+    fn crt_combined_modulus_too_large() {
+        let (p, q) = (1_000_000_007, 998_244_353);
+        let (x, m) = crt(&[(5, p), (7, q)]).unwrap();
+        assert_eq!((x % p, x % q, m), (5, 7, p * q));
+
+        assert_eq!(crt(&[(1, p), (2, q), (3, 1_000_000_009)]), None);
+    }
+
+    #[test]
+    fn crt_matches_bus_schedule() {
+        let buses = [(0, 7), (-1, 13), (-4, 59), (-6, 31), (-7, 19)];
+        assert_eq!(crt(&buses), Some((1068781, 3162341)));
+    }
+}
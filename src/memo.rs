@@ -0,0 +1,215 @@
+//! Caching for the "count the ways with recursion" puzzles.
+//!
+//! [`Memo`] is a plain map from arguments to results that knows how to drive
+//! a recursive function, and [`memoize`] is the one-shot shorthand for it.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// A memoization table, optionally bounded in size.
+///
+/// When a capacity is set and the table is full, the oldest entry is evicted
+/// to make room for the next one.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    order: VecDeque<K>,
+    capacity: Option<usize>,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            order: VecDeque::new(),
+            capacity: None,
+        }
+    }
+}
+
+impl<K, V> Memo<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    pub fn new() -> Self {
+        Memo::default()
+    }
+
+    /// A table holding at most `capacity` results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_capacity_limit(capacity: usize) -> Self {
+        assert!(capacity > 0, "memo capacity must be non-zero");
+        Memo {
+            capacity: Some(capacity),
+            ..Memo::default()
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        if self.capacity.is_some() && !self.cache.contains_key(&key) {
+            if self.capacity == Some(self.cache.len()) {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.cache.remove(&oldest);
+                }
+            }
+            self.order.push_back(key.clone());
+        }
+        self.cache.insert(key, value);
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.order.clear();
+    }
+
+    /// Returns the cached result for `key`, computing and storing it with `f`
+    /// on a miss. `f` receives the table so it can recurse through it.
+    pub fn get_or_insert_with<F>(&mut self, key: K, f: F) -> V
+    where
+        F: FnOnce(&mut Self) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+
+        let value = f(self);
+        self.insert(key, value.clone());
+        value
+    }
+
+    /// Evaluates a recursive function through the table. `f` is handed a
+    /// callback to use for its recursive calls, so every sub-result is
+    /// cached as well.
+    ///
+    /// The table outlives the call, so results carry over between calls on
+    /// the same `Memo`.
+    pub fn call<F>(&mut self, key: K, f: &F) -> V
+    where
+        F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+
+        let value = f(&mut |next| self.call(next, f), key.clone());
+        self.insert(key, value.clone());
+        value
+    }
+}
+
+/// Evaluates a recursive function with a fresh memoization table.
+///
+/// ```
+/// use aoc_rs::memo::memoize;
+///
+/// let fib = memoize(90u64, |fib, n| if n < 2 { n } else { fib(n - 1) + fib(n - 2) });
+/// assert_eq!(fib, 2_880_067_194_370_816_120);
+/// ```
+pub fn memoize<K, V, F>(key: K, f: F) -> V
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    Memo::new().call(key, &f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capacity_evicts_oldest_entry() {
+        let mut memo = Memo::with_capacity_limit(2);
+        memo.insert(1, 'a');
+        memo.insert(2, 'b');
+        memo.insert(3, 'c');
+
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.get(&1), None);
+        assert_eq!(memo.get(&2), Some(&'b'));
+        assert_eq!(memo.get(&3), Some(&'c'));
+
+        memo.insert(4, 'd');
+        assert_eq!(memo.get(&2), None);
+    }
+
+    #[test]
+    fn reinserting_a_key_does_not_evict() {
+        let mut memo = Memo::with_capacity_limit(2);
+        memo.insert(1, 'a');
+        memo.insert(2, 'b');
+        memo.insert(1, 'z');
+
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.get(&1), Some(&'z'));
+        assert_eq!(memo.order, [1, 2]);
+
+        memo.insert(3, 'c');
+        assert_eq!(memo.get(&1), None);
+        assert_eq!(memo.order, [2, 3]);
+    }
+
+    #[test]
+    fn unbounded_memo_keeps_no_order() {
+        let mut memo = Memo::new();
+        for i in 0..100 {
+            memo.insert(i, i);
+        }
+        assert_eq!(memo.len(), 100);
+        assert!(memo.order.is_empty());
+    }
+
+    #[test]
+    fn clear_resets_order() {
+        let mut memo = Memo::with_capacity_limit(1);
+        memo.insert(1, 1);
+        memo.clear();
+        assert!(memo.is_empty());
+        assert!(memo.order.is_empty());
+    }
+
+    #[test]
+    fn call_works_with_bounded_memo() {
+        let fib = |fib: &mut dyn FnMut(u64) -> u64, n: u64| {
+            if n < 2 {
+                n
+            } else {
+                fib(n - 1) + fib(n - 2)
+            }
+        };
+        let mut memo = Memo::with_capacity_limit(3);
+        assert_eq!(memo.call(40, &fib), 102_334_155);
+        assert!(memo.len() <= 3);
+    }
+
+    #[test]
+    fn get_or_insert_with_recurses_through_table() {
+        fn stairs(memo: &mut Memo<u32, u64>, n: u32) -> u64 {
+            if n == 0 {
+                return 1;
+            }
+            memo.get_or_insert_with(n, |memo| (1..=n.min(3)).map(|s| stairs(memo, n - s)).sum())
+        }
+
+        let mut memo = Memo::new();
+        assert_eq!(stairs(&mut memo, 10), 274);
+        assert_eq!(memo.len(), 10);
+    }
+}
@@ -0,0 +1,242 @@
+//! Small parsing helpers for the line formats puzzle inputs are written in.
+//!
+//! The functions here return `None` instead of panicking, so a solution can
+//! decide for itself whether a malformed line is an error or just skipped.
+
+use std::str::FromStr;
+
+/// Parses a leading, optionally signed integer and returns it with the rest
+/// of the input.
+///
+/// ```
+/// use aoc_rs::parse::signed;
+///
+/// assert_eq!(signed::<i32>("-12,4"), Some((-12, ",4")));
+/// ```
+pub fn signed<T: FromStr>(input: &str) -> Option<(T, &str)> {
+    let sign = usize::from(input.starts_with('-') || input.starts_with('+'));
+    let digits = input[sign..]
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len() - sign);
+
+    if digits == 0 {
+        return None;
+    }
+
+    let (number, rest) = input.split_at(sign + digits);
+    number.parse().ok().map(|value| (value, rest))
+}
+
+/// Parses a leading unsigned integer and returns it with the rest of the
+/// input.
+pub fn unsigned<T: FromStr>(input: &str) -> Option<(T, &str)> {
+    let digits = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+
+    if digits == 0 {
+        return None;
+    }
+
+    let (number, rest) = input.split_at(digits);
+    number.parse().ok().map(|value| (value, rest))
+}
+
+/// Strips `expected` from the front of the input, returning what follows it.
+pub fn literal<'a>(input: &'a str, expected: &str) -> Option<&'a str> {
+    input.strip_prefix(expected)
+}
+
+/// Collects every integer appearing anywhere in the input.
+///
+/// A `-` counts as a sign only when it does not directly follow a digit, so
+/// ranges such as `1-3` yield `[1, 3]`. If `T` cannot hold a negative
+/// number, the `-` is treated as a separator instead, so `ints::<u32>("a-5")`
+/// yields `[5]`. Numbers that still do not parse as `T`, such as ones that
+/// overflow it, are skipped.
+///
+/// ```
+/// use aoc_rs::parse::ints;
+///
+/// let numbers: Vec<i64> = ints("Sensor at x=2, y=-18: closest beacon 1-3");
+/// assert_eq!(numbers, vec![2, -18, 1, 3]);
+/// ```
+pub fn ints<T: FromStr>(input: &str) -> Vec<T> {
+    let bytes = input.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+
+        if negative || bytes[i].is_ascii_digit() {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            let number = input[start..i]
+                .parse()
+                .or_else(|_| input[start + usize::from(negative)..i].parse());
+            if let Ok(number) = number {
+                numbers.push(number);
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    numbers
+}
+
+/// Splits the input on `separator`, trims each piece and maps it with `f`.
+///
+/// Empty pieces are skipped, so trailing separators are harmless. Returns
+/// `None` if `f` rejects any piece.
+pub fn split_map<T, F>(input: &str, separator: &str, f: F) -> Option<Vec<T>>
+where
+    F: FnMut(&str) -> Option<T>,
+{
+    input
+        .split(separator)
+        .map(str::trim)
+        .filter(|piece| !piece.is_empty())
+        .map(f)
+        .collect()
+}
+
+/// Matches the input against a pattern where every `{}` captures text.
+///
+/// Each capture extends up to the next literal part of the pattern, so two
+/// placeholders must be separated by some literal text. The whole input has
+/// to be consumed for the match to succeed.
+///
+/// ```
+/// use aoc_rs::parse::captures;
+///
+/// let caps = captures("move {} from {} to {}", "move 3 from 1 to 2");
+/// assert_eq!(caps, Some(vec!["3", "1", "2"]));
+/// ```
+pub fn captures<'a>(pattern: &str, input: &'a str) -> Option<Vec<&'a str>> {
+    let mut parts = pattern.split("{}");
+    let mut rest = literal(input, parts.next()?)?;
+    let mut captured = Vec::new();
+    let mut parts = parts.peekable();
+
+    while let Some(part) = parts.next() {
+        let last = parts.peek().is_none();
+
+        let end = if part.is_empty() {
+            if !last {
+                return None;
+            }
+            rest.len()
+        } else if last {
+            rest.strip_suffix(part)?.len()
+        } else {
+            rest.find(part)?
+        };
+
+        captured.push(&rest[..end]);
+        rest = &rest[end + part.len()..];
+    }
+
+    rest.is_empty().then_some(captured)
+}
+
+/// Matches the input against a `{}` pattern and parses the captures into a
+/// tuple.
+///
+/// ```
+/// use aoc_rs::parse::scan;
+///
+/// let (x, y, name): (i32, i32, String) = scan("{},{} -> {}", "4,-2 -> wire").unwrap();
+/// assert_eq!((x, y, name.as_str()), (4, -2, "wire"));
+/// ```
+pub fn scan<T: FromCaptures>(pattern: &str, input: &str) -> Option<T> {
+    T::from_captures(&captures(pattern, input)?)
+}
+
+/// Conversion from the strings captured by [`captures`].
+pub trait FromCaptures: Sized {
+    fn from_captures(captures: &[&str]) -> Option<Self>;
+}
+
+macro_rules! impl_from_captures {
+    ($len:literal; $($ty:ident $idx:tt),+) => {
+        impl<$($ty: FromStr),+> FromCaptures for ($($ty,)+) {
+            fn from_captures(captures: &[&str]) -> Option<Self> {
+                if captures.len() != $len {
+                    return None;
+                }
+                Some(($(captures[$idx].trim().parse::<$ty>().ok()?,)+))
+            }
+        }
+    };
+}
+
+impl_from_captures!(1; A 0);
+impl_from_captures!(2; A 0, B 1);
+impl_from_captures!(3; A 0, B 1, C 2);
+impl_from_captures!(4; A 0, B 1, C 2, D 3);
+impl_from_captures!(5; A 0, B 1, C 2, D 3, E 4);
+impl_from_captures!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signed_requires_digits() {
+        assert_eq!(signed::<i32>("-"), None);
+        assert_eq!(signed::<i32>("+x"), None);
+        assert_eq!(signed::<i32>("+7"), Some((7, "")));
+    }
+
+    #[test]
+    fn unsigned_rejects_sign() {
+        assert_eq!(unsigned::<u32>("-5"), None);
+        assert_eq!(unsigned::<u32>("42 rest"), Some((42, " rest")));
+    }
+
+    #[test]
+    fn ints_treats_minus_as_separator_for_unsigned_targets() {
+        assert_eq!(ints::<u32>("a-5 b 7"), vec![5, 7]);
+        assert_eq!(ints::<i32>("a-5 b 7"), vec![-5, 7]);
+    }
+
+    #[test]
+    fn ints_skips_numbers_that_overflow() {
+        assert_eq!(ints::<u8>("1 300 2"), vec![1, 2]);
+    }
+
+    #[test]
+    fn split_map_rejects_bad_pieces() {
+        assert_eq!(
+            split_map("1, 2,3,", ",", |s| s.parse().ok()),
+            Some(vec![1, 2, 3])
+        );
+        assert_eq!(split_map("1,x", ",", |s| s.parse::<i32>().ok()), None);
+    }
+
+    #[test]
+    fn captures_rejects_adjacent_placeholders() {
+        assert_eq!(captures("{}{}", "12"), None);
+        assert_eq!(captures("{}", "12"), Some(vec!["12"]));
+    }
+
+    #[test]
+    fn captures_requires_whole_input() {
+        assert_eq!(captures("a={}", "b=1"), None);
+        assert_eq!(captures("a={}!", "a=1"), None);
+        assert_eq!(captures("a={},", "a=1,2,"), Some(vec!["1,2"]));
+    }
+
+    #[test]
+    fn scan_rejects_arity_mismatch() {
+        assert_eq!(scan::<(i32, i32)>("{},{},{}", "1,2,3"), None);
+        assert_eq!(scan::<(i32,)>("x={}", "x=-4"), Some((-4,)));
+    }
+}
@@ -0,0 +1,231 @@
+//! Reading the puzzle description page, `/{year}/day/{day}`.
+//!
+//! The functions take the page's HTML, however it was fetched.
+
+use crate::html::{plain_text, sections, strip_tags};
+use crate::solution::{Answer, Part};
+
+/// The puzzle's title, from its `--- Day 1: Trebuchet?! ---` heading.
+///
+/// ```
+/// use aoc_rs::puzzle::title;
+///
+/// let page = "<article class=\"day-desc\"><h2>--- Day 1: Trebuchet?! ---</h2>";
+/// assert_eq!(title(page).as_deref(), Some("Trebuchet?!"));
+/// ```
+pub fn title(page: &str) -> Option<String> {
+    sections(page, "<h2>", "</h2>").find_map(|heading| {
+        let heading = plain_text(heading);
+        let rest = heading.strip_prefix("--- Day ")?.strip_suffix(" ---")?;
+        let (_, title) = rest.split_once(": ")?;
+        Some(title.to_string())
+    })
+}
+
+/// The text of every `<pre><code>` block on the page, in order, with
+/// markup such as `<em>` removed.
+///
+/// Most puzzles give their example input in the first block, but some use
+/// several, or show intermediate states between them. So this returns all
+/// of them and leaves the caller to pick one by index.
+///
+/// ```
+/// use aoc_rs::puzzle::examples;
+///
+/// let page = "<p>For example:</p>\n<pre><code>1721\n979\n</code></pre>";
+/// assert_eq!(examples(page), vec!["1721\n979\n"]);
+/// ```
+pub fn examples(page: &str) -> Vec<String> {
+    sections(page, "<pre><code>", "</code></pre>")
+        .map(strip_tags)
+        .collect()
+}
+
+/// The answer the prose gives for a part's example, if it gives one.
+///
+/// The site highlights the example's result as `<code><em>142</em></code>`
+/// (or the reverse nesting) in the text, usually as the last highlighted
+/// value before the question. This takes the last such value in the part's
+/// prose, outside any `<pre>` block. It is a heuristic: check it against
+/// the text before relying on it in a test.
+///
+/// ```
+/// use aoc_rs::puzzle::example_answer;
+/// use aoc_rs::solution::Part;
+///
+/// let page = "<article class=\"day-desc\"><p>In this example, the calibration \
+///             values of these four lines are <code>12</code>, <code>38</code>, \
+///             <code>15</code>, and <code>77</code>. Adding these together \
+///             produces <code><em>142</em></code>.</p></article>";
+/// assert_eq!(example_answer(page, Part::One), Some("142".into()));
+/// ```
+pub fn example_answer(page: &str, part: Part) -> Option<Answer> {
+    let prose = without_pre_blocks(part_article(page, part)?);
+    let (start, close) = [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ]
+    .into_iter()
+    .filter_map(|(open, close)| Some((prose.rfind(open)? + open.len(), close)))
+    .max_by_key(|&(start, _)| start)?;
+    let len = prose[start..].find(close)?;
+    Some(strip_tags(&prose[start..start + len]).into())
+}
+
+/// The answers the site accepted, part one first, from the "Your puzzle
+/// answer was ..." lines shown under each solved part. Empty if no part is
+/// solved, and one answer on day 25, whose second star has no answer.
+///
+/// ```
+/// use aoc_rs::puzzle::accepted_answers;
+///
+/// let page = "</article>\n<p>Your puzzle answer was <code>54573</code>.</p>";
+/// assert_eq!(accepted_answers(page), vec!["54573".into()]);
+/// ```
+pub fn accepted_answers(page: &str) -> Vec<Answer> {
+    sections(page, "Your puzzle answer was <code>", "</code>")
+        .map(|answer| strip_tags(answer).into())
+        .collect()
+}
+
+/// The part an answer should be submitted for next, judged from a freshly
+/// fetched page: part one until it is solved, then part two, then `None`.
+/// Day 25's second star has no answer, so that day stops after part one.
+///
+/// Submitting to the wrong part is what the site's "You don't seem to be
+/// solving the right level" reply means.
+pub fn next_part(page: &str) -> Option<Part> {
+    match accepted_answers(page).len() {
+        0 => Some(Part::One),
+        1 if has_part_two(page) && day(page) != Some(25) => Some(Part::Two),
+        _ => None,
+    }
+}
+
+/// The day number in the page's `--- Day 1: ... ---` heading.
+fn day(page: &str) -> Option<u8> {
+    sections(page, "<h2>--- Day ", ":").next()?.parse().ok()
+}
+
+/// Whether the page shows part two, which it only does once part one is
+/// solved. A page fetched earlier has to be fetched again after solving
+/// part one.
+pub fn has_part_two(page: &str) -> bool {
+    part_article(page, Part::Two).is_some_and(|html| html.contains("--- Part Two ---"))
+}
+
+/// The HTML of one part's `<article>`, or `None` if the page does not show
+/// that part yet.
+pub fn part_article(page: &str, part: Part) -> Option<&str> {
+    let index = match part {
+        Part::One => 0,
+        Part::Two => 1,
+    };
+    sections(page, "<article class=\"day-desc\">", "</article>").nth(index)
+}
+
+fn without_pre_blocks(html: &str) -> String {
+    let mut prose = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find("<pre>") {
+        prose.push_str(&rest[..start]);
+        match rest[start..].find("</pre>") {
+            Some(end) => rest = &rest[start + end + "</pre>".len()..],
+            None => rest = "",
+        }
+    }
+    prose.push_str(rest);
+    prose
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Sonar &amp; Sweep ---</h2><p>You're minding your own business on a ship at sea...</p>
+<p>For example, suppose you had the following report:</p>
+<pre><code>199
+200
+&lt;208&gt;
+</code></pre>
+<p>In this example, there are <em><code>7</code></em> measurements that are larger than the previous measurement.</p>
+</article>
+<p>Your puzzle answer was <code>1616</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Consider sums of a three-measurement sliding window.</p>
+<pre><code><em>A:</em> 607
+</code></pre>
+<p>In this example, there are <code><em>5</em></code> sums that are larger than the previous sum.</p>
+</article>
+<p>Your puzzle answer was <code>1645</code>.</p><p class="day-success">Both parts of this puzzle are complete! They provide two gold stars: **</p>
+</main>"#;
+
+    #[test]
+    fn title_from_heading() {
+        assert_eq!(title(PAGE).as_deref(), Some("Sonar & Sweep"));
+        assert_eq!(
+            title("<h2>--- Day 25: Snowverload: The Sequel ---</h2>").as_deref(),
+            Some("Snowverload: The Sequel")
+        );
+    }
+
+    #[test]
+    fn example_blocks_in_order() {
+        assert_eq!(examples(PAGE), vec!["199\n200\n<208>\n", "A: 607\n"]);
+        assert!(examples("<p>No examples today.</p>").is_empty());
+    }
+
+    #[test]
+    fn example_answers_per_part() {
+        assert_eq!(example_answer(PAGE, Part::One), Some("7".into()));
+        assert_eq!(example_answer(PAGE, Part::Two), Some("5".into()));
+    }
+
+    #[test]
+    fn example_answer_ignores_pre_blocks() {
+        let page = "<article class=\"day-desc\"><p>See below.</p>\
+                    <pre><code><em>#</em>..</code></pre></article>";
+        assert_eq!(example_answer(page, Part::One), None);
+        assert_eq!(example_answer(page, Part::Two), None);
+    }
+
+    #[test]
+    fn part_two_only_after_part_one() {
+        assert!(has_part_two(PAGE));
+        assert!(part_article(PAGE, Part::Two)
+            .unwrap()
+            .contains("sliding window"));
+
+        let part_one_only = &PAGE[..PAGE.find("<p>Your puzzle answer").unwrap()];
+        assert!(!has_part_two(part_one_only));
+        assert_eq!(part_article(part_one_only, Part::Two), None);
+        assert!(part_article(part_one_only, Part::One).is_some());
+    }
+
+    #[test]
+    fn accepted_answers_in_part_order() {
+        assert_eq!(
+            accepted_answers(PAGE),
+            vec![Answer::from(1616), Answer::from(1645)]
+        );
+        let unsolved = &PAGE[..PAGE.find("<p>Your puzzle answer").unwrap()];
+        assert!(accepted_answers(unsolved).is_empty());
+    }
+
+    #[test]
+    fn next_part_follows_solved_parts() {
+        let end = |marker| &PAGE[..PAGE.find(marker).unwrap()];
+        assert_eq!(next_part(end("<p>Your puzzle answer")), Some(Part::One));
+        assert_eq!(next_part(end("<p class=\"day-success\">")), None);
+        let after_part_one = PAGE.replacen("<code>1645</code>", "", 1);
+        assert_eq!(next_part(&after_part_one), Some(Part::Two));
+
+        let christmas = after_part_one.replace("Day 1: Sonar", "Day 25: Sonar");
+        assert_eq!(next_part(&christmas), None);
+    }
+
+    #[test]
+    fn no_title() {
+        assert_eq!(title("<h2 id=\"part2\">--- Part Two ---</h2>"), None);
+        assert_eq!(title("<html>404 Not Found</html>"), None);
+    }
+}
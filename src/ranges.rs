@@ -0,0 +1,281 @@
+//! Inclusive integer intervals and sets of them.
+//!
+//! Puzzles describe ranges with both ends included (`3-7`, `x=-2..24`), so
+//! [`Interval`] is inclusive too, and [`RangeSet`] keeps its intervals sorted
+//! and merged so coverage questions reduce to a walk over a short `Vec`.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    /// # Panics
+    ///
+    /// Panics if `start > end`.
+    pub fn new(start: i64, end: i64) -> Self {
+        assert!(start <= end, "interval start {start} is after end {end}");
+        Interval { start, end }
+    }
+
+    /// The interval covering `len` values beginning at `start`, or `None` if
+    /// `len` is zero.
+    pub fn from_start_len(start: i64, len: u64) -> Option<Self> {
+        let last = i64::try_from(len.checked_sub(1)?).ok()?;
+        Some(Interval::new(start, start.checked_add(last)?))
+    }
+
+    /// Number of integers in the interval. This is a `u128` because
+    /// `i64::MIN..=i64::MAX` holds one more value than a `u64` can count.
+    pub fn size(&self) -> u128 {
+        u128::from(self.end.abs_diff(self.start)) + 1
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        self.overlaps(other)
+            .then(|| Interval::new(self.start.max(other.start), self.end.min(other.end)))
+    }
+
+    /// The parts of `self` not covered by `other`: what remains below it and
+    /// what remains above it.
+    ///
+    /// ```
+    /// use aoc_rs::ranges::Interval;
+    ///
+    /// let (below, above) = Interval::new(0, 10).subtract(&Interval::new(3, 4));
+    /// assert_eq!(below, Some(Interval::new(0, 2)));
+    /// assert_eq!(above, Some(Interval::new(5, 10)));
+    /// ```
+    pub fn subtract(&self, other: &Interval) -> (Option<Interval>, Option<Interval>) {
+        if !self.overlaps(other) {
+            return if self.end < other.start {
+                (Some(*self), None)
+            } else {
+                (None, Some(*self))
+            };
+        }
+
+        let below = (self.start < other.start).then(|| Interval::new(self.start, other.start - 1));
+        let above = (other.end < self.end).then(|| Interval::new(other.end + 1, self.end));
+        (below, above)
+    }
+
+    /// Moves both ends by `offset`, as the seed-to-soil style mappings do.
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+/// A set of integers stored as sorted, non-overlapping, non-adjacent
+/// intervals.
+///
+/// ```
+/// use aoc_rs::ranges::{Interval, RangeSet};
+///
+/// let mut covered = RangeSet::new();
+/// covered.insert(Interval::new(12, 12));
+/// covered.insert(Interval::new(2, 14));
+/// covered.insert(Interval::new(16, 24));
+/// covered.insert(Interval::new(15, 15));
+///
+/// assert_eq!(covered.intervals(), &[Interval::new(2, 24)]);
+/// assert_eq!(covered.len(), 23);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        RangeSet::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Total number of integers in the set.
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(Interval::size).sum()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.intervals.partition_point(|i| i.end < value);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    /// Adds an interval, merging it with any intervals it overlaps or
+    /// touches.
+    pub fn insert(&mut self, interval: Interval) {
+        let first = self
+            .intervals
+            .partition_point(|i| i.end.saturating_add(1) < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|i| i.start <= interval.end.saturating_add(1));
+
+        let merged = self.intervals[first..last].iter().fold(interval, |acc, i| {
+            Interval::new(acc.start.min(i.start), acc.end.max(i.end))
+        });
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Removes every value covered by `interval`.
+    pub fn remove(&mut self, interval: Interval) {
+        self.intervals = self
+            .intervals
+            .iter()
+            .flat_map(|i| {
+                let (below, above) = i.subtract(&interval);
+                below.into_iter().chain(above)
+            })
+            .collect();
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut result = self.clone();
+        result.extend(other.intervals.iter().copied());
+        result
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (0, 0);
+
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (left, right) = (self.intervals[a], other.intervals[b]);
+            intervals.extend(left.intersection(&right));
+            if left.end < right.end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+
+        RangeSet { intervals }
+    }
+
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut result = self.clone();
+        for interval in &other.intervals {
+            result.remove(*interval);
+        }
+        result
+    }
+}
+
+impl Extend<Interval> for RangeSet {
+    fn extend<T: IntoIterator<Item = Interval>>(&mut self, iter: T) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl FromIterator<Interval> for RangeSet {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        let mut set = RangeSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> RangeSet {
+        intervals
+            .iter()
+            .map(|&(a, b)| Interval::new(a, b))
+            .collect()
+    }
+
+    #[test]
+    fn size_of_full_range() {
+        let full = Interval::new(i64::MIN, i64::MAX);
+        assert_eq!(full.size(), 1 << 64);
+        assert_eq!(set(&[(i64::MIN, -1), (0, i64::MAX)]).len(), 1 << 64);
+    }
+
+    #[test]
+    fn insert_merges_adjacent_and_overlapping() {
+        assert_eq!(set(&[(1, 3), (4, 6)]).intervals(), &[Interval::new(1, 6)]);
+        assert_eq!(set(&[(1, 3), (5, 6)]).intervals().len(), 2);
+        assert_eq!(
+            set(&[(1, 3), (5, 6), (10, 12), (2, 10)]).intervals(),
+            &[Interval::new(1, 12)]
+        );
+        assert_eq!(
+            set(&[(i64::MAX - 1, i64::MAX), (i64::MIN, i64::MIN)]).len(),
+            3
+        );
+    }
+
+    #[test]
+    fn remove_splits_an_interval() {
+        let mut covered = set(&[(0, 10)]);
+        covered.remove(Interval::new(4, 6));
+        assert_eq!(covered, set(&[(0, 3), (7, 10)]));
+        covered.remove(Interval::new(-5, 0));
+        assert_eq!(covered, set(&[(1, 3), (7, 10)]));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 4), (26, 30)]));
+        assert_eq!(b.difference(&a), set(&[(11, 19)]));
+        assert_eq!(a.union(&b), set(&[(0, 30)]));
+        assert!(a.intersection(&set(&[(11, 19)])).is_empty());
+    }
+
+    #[test]
+    fn contains_checks_every_interval() {
+        let a = set(&[(0, 10), (20, 30)]);
+        assert!(a.contains(0) && a.contains(20) && a.contains(30));
+        assert!(!a.contains(15) && !a.contains(-1) && !a.contains(31));
+    }
+
+    #[test]
+    fn subtract_outside_and_covering() {
+        let i = Interval::new(5, 7);
+        assert_eq!(i.subtract(&Interval::new(0, 10)), (None, None));
+        assert_eq!(i.subtract(&Interval::new(8, 9)), (Some(i), None));
+        assert_eq!(i.subtract(&Interval::new(0, 4)), (None, Some(i)));
+    }
+
+    #[test]
+    fn from_start_len_bounds() {
+        assert_eq!(Interval::from_start_len(5, 0), None);
+        assert_eq!(Interval::from_start_len(5, 3), Some(Interval::new(5, 7)));
+        assert_eq!(Interval::from_start_len(i64::MAX, 2), None);
+    }
+}
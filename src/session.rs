@@ -0,0 +1,170 @@
+//! Finding the session cookie that authenticates requests to the site.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The session cookie's value.
+///
+/// `Debug` and `Display` print `session=***`, so the token cannot leak
+/// through a log line, an error message or a derived `Debug` on a type that
+/// holds it. [`expose`](Session::expose) is the only way to read it.
+///
+/// ```
+/// use aoc_rs::session::Session;
+///
+/// let session = Session::new("53616c746564");
+/// assert_eq!(format!("{session:?}"), "session=***");
+/// assert_eq!(session.cookie_header(), "session=53616c746564");
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Session(String);
+
+impl Session {
+    /// Wraps a token, trimming surrounding whitespace.
+    pub fn new(token: impl Into<String>) -> Session {
+        let token = token.into();
+        Session(token.trim().to_string())
+    }
+
+    /// The raw token, for the one place that sends it.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// The value for a `Cookie` request header.
+    pub fn cookie_header(&self) -> String {
+        format!("session={}", self.0)
+    }
+}
+
+impl fmt::Debug for Session {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("session=***")
+    }
+}
+
+impl fmt::Display for Session {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("session=***")
+    }
+}
+
+/// Environment variables checked for the session token, in order.
+pub const ENV_VARS: [&str; 2] = ["AOC_SESSION", "ADVENT_OF_CODE_SESSION"];
+
+/// Looks for the session token in, in order:
+///
+/// 1. the [`ENV_VARS`],
+/// 2. `~/.config/aoc/session`,
+/// 3. `.aoc-session` in the current directory.
+///
+/// Values are trimmed, and empty ones are skipped. Returns `None` if no
+/// source has a token.
+pub fn discover() -> Option<Session> {
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from);
+    let cwd = env::current_dir().unwrap_or_default();
+    discover_with(|name| env::var(name).ok(), home.as_deref(), &cwd)
+}
+
+fn discover_with(
+    var: impl Fn(&str) -> Option<String>,
+    home: Option<&Path>,
+    cwd: &Path,
+) -> Option<Session> {
+    let from_env = ENV_VARS.iter().filter_map(|name| var(name));
+    let files = home
+        .map(|home| home.join(".config").join("aoc").join("session"))
+        .into_iter()
+        .chain([cwd.join(".aoc-session")]);
+    let from_files = files.filter_map(|path| fs::read_to_string(path).ok());
+
+    from_env
+        .chain(from_files)
+        .map(Session::new)
+        .find(|session| !session.0.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let path = env::temp_dir().join(format!("aoc-rs-{name}-{}", std::process::id()));
+            fs::create_dir_all(&path).unwrap();
+            TempDir(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    fn session(token: &str) -> Option<Session> {
+        Some(Session::new(token))
+    }
+
+    #[test]
+    fn token_is_redacted() {
+        let token = Session::new(" 53616c746564\n");
+        assert_eq!(token.expose(), "53616c746564");
+        assert_eq!(token.to_string(), "session=***");
+        assert_eq!(format!("{:?}", Some(&token)), "Some(session=***)");
+        assert!(!format!("{token:#?}").contains("5361"));
+    }
+
+    #[test]
+    fn env_vars_come_first() {
+        let dir = TempDir::new("session-env");
+        fs::write(dir.0.join(".aoc-session"), "from-file").unwrap();
+
+        let env = |name: &str| (name == "ADVENT_OF_CODE_SESSION").then(|| "second\n".to_string());
+        assert_eq!(discover_with(env, None, &dir.0), session("second"));
+
+        let both = |name: &str| Some(format!("{name}-token"));
+        assert_eq!(
+            discover_with(both, None, &dir.0),
+            session("AOC_SESSION-token")
+        );
+    }
+
+    #[test]
+    fn config_file_before_project_file() {
+        let home = TempDir::new("session-home");
+        let cwd = TempDir::new("session-cwd");
+        fs::write(cwd.0.join(".aoc-session"), "project\n").unwrap();
+        assert_eq!(
+            discover_with(no_env, Some(&home.0), &cwd.0),
+            session("project")
+        );
+
+        let config = home.0.join(".config").join("aoc");
+        fs::create_dir_all(&config).unwrap();
+        fs::write(config.join("session"), "  user  ").unwrap();
+        assert_eq!(
+            discover_with(no_env, Some(&home.0), &cwd.0),
+            session("user")
+        );
+    }
+
+    #[test]
+    fn blank_sources_are_skipped() {
+        let cwd = TempDir::new("session-blank");
+        fs::write(cwd.0.join(".aoc-session"), "\n").unwrap();
+        let blank = |_: &str| Some(" ".to_string());
+        assert_eq!(discover_with(blank, None, &cwd.0), None);
+        assert_eq!(discover_with(no_env, None, Path::new("/nonexistent")), None);
+    }
+}
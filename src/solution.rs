@@ -0,0 +1,519 @@
+//! Puzzle solvers and a registry that looks them up by date.
+//!
+//! A [`Solution`] turns the raw input into an [`Answer`] for each part, and a
+//! [`Registry`] maps each `(Year, Day)` to the solver for that puzzle.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::date::{Day, Year};
+
+/// One of the two halves of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+/// A solver's result, in the text form the site expects.
+///
+/// Integers and strings convert with `into()`, so a part can end with
+/// `count.into()` or `code.into()`.
+///
+/// ```
+/// use aoc_rs::solution::Answer;
+///
+/// let answer: Answer = 42u64.into();
+/// assert_eq!(answer.as_str(), "42");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Answer(String);
+
+impl Answer {
+    /// Builds an answer from anything printable, ready to submit.
+    ///
+    /// Surrounding whitespace such as a trailing newline is trimmed. Text
+    /// that is empty, or that looks like a `Debug`-formatted wrapper such as
+    /// `Some(42)` or `"abc"`, is rejected, since the site would only count it
+    /// as a wrong guess.
+    ///
+    /// ```
+    /// use aoc_rs::solution::{Answer, InvalidAnswer};
+    ///
+    /// assert_eq!(Answer::checked("1234\n"), Ok(Answer::from(1234)));
+    ///
+    /// let debug = format!("{:?}", Some(42));
+    /// assert_eq!(Answer::checked(debug), Err(InvalidAnswer::DebugFormatted("Some(42)".into())));
+    /// ```
+    pub fn checked<A: ToString>(answer: A) -> Result<Answer, InvalidAnswer> {
+        let text = answer.to_string();
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(InvalidAnswer::Empty);
+        }
+        let wrapped = ["Some(", "Ok(", "Err("]
+            .iter()
+            .any(|prefix| text.starts_with(prefix) && text.ends_with(')'));
+        let quoted = text.len() >= 2 && text.starts_with('"') && text.ends_with('"');
+        if wrapped || quoted || text == "None" || text == "()" {
+            return Err(InvalidAnswer::DebugFormatted(text.to_string()));
+        }
+        Ok(Answer(text.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Why [`Answer::checked`] refused an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidAnswer {
+    /// Nothing was left after trimming.
+    Empty,
+    /// The text looks like `Debug` output rather than a value.
+    DebugFormatted(String),
+}
+
+impl fmt::Display for InvalidAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidAnswer::Empty => write!(f, "answer is empty"),
+            InvalidAnswer::DebugFormatted(text) => {
+                write!(f, "answer {text:?} looks like Debug output, not a value")
+            }
+        }
+    }
+}
+
+impl Error for InvalidAnswer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Answer(answer)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Self {
+        Answer(answer.to_string())
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(answer: $t) -> Self {
+                    Answer(answer.to_string())
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// The solver for one puzzle.
+///
+/// The input is parsed once and both parts borrow the result.
+///
+/// ```
+/// use aoc_rs::solution::{Answer, Solution};
+///
+/// struct Sonar;
+///
+/// impl Solution for Sonar {
+///     type Parsed = Vec<u32>;
+///
+///     fn parse(input: &str) -> Vec<u32> {
+///         input.lines().map(|line| line.parse().unwrap()).collect()
+///     }
+///
+///     fn part1(depths: &Vec<u32>) -> Answer {
+///         depths.windows(2).filter(|w| w[0] < w[1]).count().into()
+///     }
+///
+///     fn part2(depths: &Vec<u32>) -> Answer {
+///         depths.windows(4).filter(|w| w[0] < w[3]).count().into()
+///     }
+/// }
+///
+/// let depths = Sonar::parse("199\n200\n208\n210\n200\n207");
+/// assert_eq!(Sonar::part1(&depths).as_str(), "4");
+/// ```
+pub trait Solution {
+    type Parsed;
+
+    fn parse(input: &str) -> Self::Parsed;
+    fn part1(parsed: &Self::Parsed) -> Answer;
+    fn part2(parsed: &Self::Parsed) -> Answer;
+}
+
+/// How one part fared in [`Registry::run_all`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Answer),
+    /// The solver panicked, with this message.
+    Failed(String),
+}
+
+/// Shows the answer, or `failed: <message>`.
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Solved(answer) => fmt::Display::fmt(answer, f),
+            Outcome::Failed(message) => write!(f, "failed: {message}"),
+        }
+    }
+}
+
+/// Both parts of one puzzle from [`Registry::run_all`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub year: Year,
+    pub day: Day,
+    pub part1: Outcome,
+    pub part2: Outcome,
+}
+
+#[derive(Clone, Copy)]
+struct Entry {
+    both: fn(&str) -> (Answer, Answer),
+    part: fn(&str, Part) -> Answer,
+    isolated: fn(&str) -> (Outcome, Outcome),
+}
+
+fn run_both<S: Solution>(input: &str) -> (Answer, Answer) {
+    let parsed = S::parse(input);
+    (S::part1(&parsed), S::part2(&parsed))
+}
+
+fn run_isolated<S: Solution>(input: &str) -> (Outcome, Outcome) {
+    let parsed = match panic::catch_unwind(|| S::parse(input)) {
+        Ok(parsed) => parsed,
+        Err(payload) => {
+            let message = panic_message(payload);
+            return (Outcome::Failed(message.clone()), Outcome::Failed(message));
+        }
+    };
+    let part = |solve: fn(&S::Parsed) -> Answer| match panic::catch_unwind(AssertUnwindSafe(|| {
+        solve(&parsed)
+    })) {
+        Ok(answer) => Outcome::Solved(answer),
+        Err(payload) => Outcome::Failed(panic_message(payload)),
+    };
+    (part(S::part1), part(S::part2))
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "non-string panic payload".to_string(),
+        },
+    }
+}
+
+fn run_part<S: Solution>(input: &str, part: Part) -> Answer {
+    let parsed = S::parse(input);
+    match part {
+        Part::One => S::part1(&parsed),
+        Part::Two => S::part2(&parsed),
+    }
+}
+
+/// Solvers keyed by the puzzle they solve, iterated in date order.
+///
+/// ```
+/// use aoc_rs::date::{Day, Year};
+/// use aoc_rs::solution::{Answer, Part, Registry, Solution};
+///
+/// struct Echo;
+///
+/// impl Solution for Echo {
+///     type Parsed = String;
+///
+///     fn parse(input: &str) -> String {
+///         input.trim().to_string()
+///     }
+///
+///     fn part1(input: &String) -> Answer {
+///         input.as_str().into()
+///     }
+///
+///     fn part2(input: &String) -> Answer {
+///         input.len().into()
+///     }
+/// }
+///
+/// let (year, day) = (Year::new(2015).unwrap(), Day::new(1).unwrap());
+/// let mut registry = Registry::new();
+/// registry.register::<Echo>(year, day);
+///
+/// let answer = registry.run_part(year, day, Part::Two, "abc\n");
+/// assert_eq!(answer, Some(3usize.into()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Registry {
+    solutions: BTreeMap<(Year, Day), Entry>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Registers `S` as the solver for `day` of `year`, replacing any solver
+    /// already there. Returns whether one was replaced.
+    pub fn register<S: Solution>(&mut self, year: Year, day: Day) -> bool {
+        let entry = Entry {
+            both: run_both::<S>,
+            part: run_part::<S>,
+            isolated: run_isolated::<S>,
+        };
+        self.solutions.insert((year, day), entry).is_some()
+    }
+
+    pub fn contains(&self, year: Year, day: Day) -> bool {
+        self.solutions.contains_key(&(year, day))
+    }
+
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+
+    /// The registered puzzles, earliest first.
+    pub fn days(&self) -> impl Iterator<Item = (Year, Day)> + '_ {
+        self.solutions.keys().copied()
+    }
+
+    /// Solves both parts of a puzzle, parsing `input` once. Returns `None`
+    /// if no solver is registered for it.
+    pub fn run(&self, year: Year, day: Day, input: &str) -> Option<(Answer, Answer)> {
+        let entry = self.solutions.get(&(year, day))?;
+        Some((entry.both)(input))
+    }
+
+    /// Solves one part of a puzzle. Returns `None` if no solver is
+    /// registered for it.
+    pub fn run_part(&self, year: Year, day: Day, part: Part, input: &str) -> Option<Answer> {
+        let entry = self.solutions.get(&(year, day))?;
+        Some((entry.part)(input, part))
+    }
+
+    /// Solves every registered puzzle that `input` has text for, in date
+    /// order. A panic in one parse or part is caught and reported as
+    /// [`Outcome::Failed`] instead of stopping the batch; the panic hook
+    /// still prints it as usual.
+    pub fn run_all(&self, mut input: impl FnMut(Year, Day) -> Option<String>) -> Vec<Report> {
+        self.solutions
+            .iter()
+            .filter_map(|(&(year, day), entry)| {
+                let (part1, part2) = (entry.isolated)(&input(year, day)?);
+                Some(Report {
+                    year,
+                    day,
+                    part1,
+                    part2,
+                })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.solutions.keys()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<i64>;
+
+        fn parse(input: &str) -> Vec<i64> {
+            input
+                .split(',')
+                .map(|n| n.trim().parse().unwrap())
+                .collect()
+        }
+
+        fn part1(numbers: &Vec<i64>) -> Answer {
+            numbers.iter().sum::<i64>().into()
+        }
+
+        fn part2(numbers: &Vec<i64>) -> Answer {
+            numbers.iter().product::<i64>().into()
+        }
+    }
+
+    struct Shout;
+
+    impl Solution for Shout {
+        type Parsed = String;
+
+        fn parse(input: &str) -> String {
+            input.trim().to_string()
+        }
+
+        fn part1(text: &String) -> Answer {
+            text.to_uppercase().into()
+        }
+
+        fn part2(text: &String) -> Answer {
+            text.as_str().into()
+        }
+    }
+
+    struct Fragile;
+
+    impl Solution for Fragile {
+        type Parsed = u32;
+
+        fn parse(input: &str) -> u32 {
+            input.trim().parse().expect("input is not a number")
+        }
+
+        fn part1(n: &u32) -> Answer {
+            (n * 2).into()
+        }
+
+        fn part2(_: &u32) -> Answer {
+            panic!("part two is not done yet")
+        }
+    }
+
+    fn date(year: u16, day: u8) -> (Year, Day) {
+        (Year::new(year).unwrap(), Day::new(day).unwrap())
+    }
+
+    #[test]
+    fn answers_from_numbers_and_text() {
+        assert_eq!(Answer::from(-3i32).as_str(), "-3");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from("abc"), Answer::from("abc".to_string()));
+    }
+
+    #[test]
+    fn checked_answers_are_trimmed() {
+        assert_eq!(Answer::checked(42u64), Ok(Answer::from(42u64)));
+        assert_eq!(Answer::checked("  -7\r\n"), Ok(Answer::from("-7")));
+        assert_eq!(Answer::checked("EFUGLPAP\n"), Ok(Answer::from("EFUGLPAP")));
+        assert_eq!(Answer::checked("3,1,4"), Ok(Answer::from("3,1,4")));
+    }
+
+    #[test]
+    fn checked_rejects_empty_and_debug_output() {
+        assert_eq!(Answer::checked(" \n"), Err(InvalidAnswer::Empty));
+        for text in ["Some(42)", "None", "Ok(1)", "Err(\"x\")", "\"abc\"", "()"] {
+            assert_eq!(
+                Answer::checked(text),
+                Err(InvalidAnswer::DebugFormatted(text.to_string()))
+            );
+        }
+        assert_eq!(
+            Answer::checked(format!("{:?}\n", Some(5))),
+            Err(InvalidAnswer::DebugFormatted("Some(5)".to_string()))
+        );
+    }
+
+    #[test]
+    fn runs_the_registered_solver() {
+        let (year, day) = date(2020, 1);
+        let mut registry = Registry::new();
+        assert!(!registry.register::<Sum>(year, day));
+
+        assert_eq!(
+            registry.run(year, day, "2, 3, 4"),
+            Some((9.into(), 24.into()))
+        );
+        assert_eq!(
+            registry.run_part(year, day, Part::Two, "2, 3, 4"),
+            Some(24.into())
+        );
+    }
+
+    #[test]
+    fn missing_day_is_none() {
+        let mut registry = Registry::new();
+        let (year, day) = date(2020, 1);
+        registry.register::<Sum>(year, day);
+
+        let (other_year, other_day) = date(2019, 2);
+        assert!(registry.contains(year, day));
+        assert!(!registry.contains(year, other_day));
+        assert_eq!(registry.run(year, other_day, "1"), None);
+        assert_eq!(registry.run_part(other_year, day, Part::One, "1"), None);
+    }
+
+    #[test]
+    fn register_replaces_and_days_are_ordered() {
+        let mut registry = Registry::new();
+        let (y2016, d5) = date(2016, 5);
+        let (y2015, d9) = date(2015, 9);
+        registry.register::<Sum>(y2016, d5);
+        registry.register::<Sum>(y2015, d9);
+        assert!(registry.register::<Shout>(y2016, d5));
+
+        assert_eq!(registry.len(), 2);
+        assert_eq!(
+            registry.days().collect::<Vec<_>>(),
+            vec![(y2015, d9), (y2016, d5)]
+        );
+        assert_eq!(
+            registry.run_part(y2016, d5, Part::One, "hi\n"),
+            Some("HI".into())
+        );
+    }
+
+    #[test]
+    fn run_all_isolates_panics() {
+        let mut registry = Registry::new();
+        let (y2015, d1) = date(2015, 1);
+        let (y2016, d2) = date(2016, 2);
+        let (y2017, d3) = date(2017, 3);
+        registry.register::<Fragile>(y2015, d1);
+        registry.register::<Fragile>(y2016, d2);
+        registry.register::<Sum>(y2017, d3);
+
+        let reports = registry.run_all(|year, _| match year.get() {
+            2015 => Some("21".to_string()),
+            2016 => Some("twenty".to_string()),
+            _ => None,
+        });
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].part1, Outcome::Solved(42.into()));
+        assert_eq!(
+            reports[0].part2,
+            Outcome::Failed("part two is not done yet".to_string())
+        );
+        assert_eq!((reports[1].year, reports[1].day), (y2016, d2));
+        assert!(matches!(&reports[1].part1, Outcome::Failed(m) if m.starts_with("input is not")));
+        assert_eq!(reports[1].part1, reports[1].part2);
+        assert_eq!(
+            reports[0].part2.to_string(),
+            "failed: part two is not done yet"
+        );
+    }
+}
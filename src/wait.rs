@@ -0,0 +1,300 @@
+//! Cooldowns reported by the server, such as the wait after a wrong answer,
+//! and the client-side throttle that keeps requests spaced out.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long to wait before the next attempt.
+///
+/// A thin wrapper over [`Duration`] that converts both ways. Adding a
+/// `Duration` applies jitter, and [`as_duration`](WaitTime::as_duration)
+/// gives the value `std::thread::sleep` or `tokio::time::sleep` expects.
+///
+/// ```
+/// use std::time::Duration;
+/// use aoc_rs::wait::WaitTime;
+///
+/// let wait = WaitTime::from_secs(300) + Duration::from_millis(1500);
+/// assert_eq!(wait.to_string(), "5m 1s");
+/// assert_eq!(Duration::from(wait), Duration::from_millis(301_500));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct WaitTime(Duration);
+
+impl WaitTime {
+    pub const ZERO: WaitTime = WaitTime(Duration::ZERO);
+
+    pub const fn new(duration: Duration) -> Self {
+        WaitTime(duration)
+    }
+
+    pub const fn from_secs(secs: u64) -> Self {
+        WaitTime(Duration::from_secs(secs))
+    }
+
+    pub const fn from_minutes_secs(minutes: u64, secs: u64) -> Self {
+        WaitTime(Duration::from_secs(minutes * 60 + secs))
+    }
+
+    pub const fn as_duration(self) -> Duration {
+        self.0
+    }
+
+    pub const fn as_secs(self) -> u64 {
+        self.0.as_secs()
+    }
+
+    pub const fn is_zero(self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// The wait left after `elapsed` has passed, stopping at zero.
+    pub fn saturating_sub(self, elapsed: Duration) -> WaitTime {
+        WaitTime(self.0.saturating_sub(elapsed))
+    }
+}
+
+impl From<Duration> for WaitTime {
+    fn from(duration: Duration) -> Self {
+        WaitTime(duration)
+    }
+}
+
+impl From<WaitTime> for Duration {
+    fn from(wait: WaitTime) -> Duration {
+        wait.0
+    }
+}
+
+impl Add<Duration> for WaitTime {
+    type Output = WaitTime;
+
+    fn add(self, rhs: Duration) -> WaitTime {
+        WaitTime(self.0 + rhs)
+    }
+}
+
+impl AddAssign<Duration> for WaitTime {
+    fn add_assign(&mut self, rhs: Duration) {
+        self.0 += rhs;
+    }
+}
+
+impl Add for WaitTime {
+    type Output = WaitTime;
+
+    fn add(self, rhs: WaitTime) -> WaitTime {
+        WaitTime(self.0 + rhs.0)
+    }
+}
+
+impl Sub<Duration> for WaitTime {
+    type Output = WaitTime;
+
+    /// # Panics
+    ///
+    /// Panics if the result would be negative; see
+    /// [`saturating_sub`](WaitTime::saturating_sub).
+    fn sub(self, rhs: Duration) -> WaitTime {
+        WaitTime(self.0 - rhs)
+    }
+}
+
+/// Formats like the site does: `"5m 0s"`, or just `"42s"` under a minute.
+/// Sub-second parts are dropped.
+impl fmt::Display for WaitTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secs = self.0.as_secs();
+        if secs < 60 {
+            write!(f, "{secs}s")
+        } else {
+            write!(f, "{}m {}s", secs / 60, secs % 60)
+        }
+    }
+}
+
+/// Spaces out requests to the site so that a loop over many days cannot
+/// hammer it.
+///
+/// Each [`reserve`](Throttle::reserve) books the next slot at least
+/// `interval` after the previous one and returns how long to wait for it, so
+/// the caller can sleep with whatever runtime it uses.
+/// [`wait`](Throttle::wait) does the blocking sleep itself.
+///
+/// ```
+/// use std::time::Duration;
+/// use aoc_rs::wait::Throttle;
+///
+/// let mut throttle = Throttle::new(Duration::from_secs(60));
+/// assert!(throttle.reserve().is_zero());
+/// assert!(throttle.reserve().as_secs() >= 59);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Throttle {
+    interval: Duration,
+    next: Option<Instant>,
+}
+
+impl Throttle {
+    /// One request per second, unless configured otherwise.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+
+    pub fn new(interval: Duration) -> Self {
+        Throttle {
+            interval,
+            next: None,
+        }
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Claims the next request slot and returns the wait until it.
+    pub fn reserve(&mut self) -> WaitTime {
+        self.reserve_at(Instant::now())
+    }
+
+    /// Blocks the current thread until the next request slot.
+    pub fn wait(&mut self) {
+        let wait = self.reserve();
+        if !wait.is_zero() {
+            thread::sleep(wait.as_duration());
+        }
+    }
+
+    fn reserve_at(&mut self, now: Instant) -> WaitTime {
+        let slot = self.next.map_or(now, |next| next.max(now));
+        self.next = Some(slot + self.interval);
+        WaitTime(slot - now)
+    }
+}
+
+impl Default for Throttle {
+    fn default() -> Self {
+        Throttle::new(Throttle::DEFAULT_INTERVAL)
+    }
+}
+
+/// Reads the remaining cooldown from the server's "answered too recently"
+/// message, which says `You have 1m 5s left to wait.`, or just `42s` under a
+/// minute. Returns `None` if the text has no such phrase.
+///
+/// ```
+/// use aoc_rs::wait::{parse_cooldown, WaitTime};
+///
+/// let page = "You gave an answer too recently; you have to wait after \
+///             submitting an answer before trying again.  You have 1m 5s \
+///             left to wait.";
+/// assert_eq!(parse_cooldown(page), Some(WaitTime::from_minutes_secs(1, 5)));
+/// ```
+pub fn parse_cooldown(text: &str) -> Option<WaitTime> {
+    let text = text.to_ascii_lowercase();
+    let before = &text[..text.find("left to wait")?];
+    let amount = &before[before.rfind("you have ")? + "you have ".len()..];
+
+    let mut secs = 0u64;
+    let mut parts = 0;
+    for part in amount.split_whitespace() {
+        let part_secs = if let Some(minutes) = part.strip_suffix('m') {
+            minutes.parse::<u64>().ok()?.checked_mul(60)?
+        } else {
+            part.strip_suffix('s')?.parse().ok()?
+        };
+        secs = secs.checked_add(part_secs)?;
+        parts += 1;
+    }
+    (parts > 0).then_some(WaitTime::from_secs(secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(WaitTime::from_secs(300).to_string(), "5m 0s");
+        assert_eq!(WaitTime::from_minutes_secs(1, 30).to_string(), "1m 30s");
+        assert_eq!(WaitTime::from_secs(42).to_string(), "42s");
+        assert_eq!(WaitTime::ZERO.to_string(), "0s");
+        assert_eq!(
+            WaitTime::new(Duration::from_millis(59_999)).to_string(),
+            "59s"
+        );
+    }
+
+    #[test]
+    fn cooldown_minutes_and_seconds() {
+        let page = "<article><p>You gave an answer too recently; you have to wait \
+                    after submitting an answer before trying again.  You have 1m 5s \
+                    left to wait. <a href=\"/2021/day/1\">[Return to Day 1]</a></p></article>";
+        assert_eq!(
+            parse_cooldown(page),
+            Some(WaitTime::from_minutes_secs(1, 5))
+        );
+        assert_eq!(
+            parse_cooldown("You have 5m 0s left to wait."),
+            Some(WaitTime::from_secs(300))
+        );
+    }
+
+    #[test]
+    fn cooldown_seconds_only() {
+        assert_eq!(
+            parse_cooldown("You gave an answer too recently. You have 42s left to wait."),
+            Some(WaitTime::from_secs(42))
+        );
+    }
+
+    #[test]
+    fn cooldown_absent_or_malformed() {
+        assert_eq!(parse_cooldown("That's the right answer!"), None);
+        assert_eq!(parse_cooldown("You have left to wait."), None);
+        assert_eq!(parse_cooldown("You have 5 minutes left to wait."), None);
+        assert_eq!(parse_cooldown("You have 5é left to wait."), None);
+        assert_eq!(parse_cooldown(""), None);
+    }
+
+    #[test]
+    fn throttle_spaces_out_reservations() {
+        let start = Instant::now();
+        let mut throttle = Throttle::new(Duration::from_secs(2));
+        assert_eq!(throttle.reserve_at(start), WaitTime::ZERO);
+        assert_eq!(throttle.reserve_at(start), WaitTime::from_secs(2));
+        assert_eq!(throttle.reserve_at(start), WaitTime::from_secs(4));
+        // A caller that comes back late is not made to wait.
+        let later = start + Duration::from_secs(10);
+        assert_eq!(throttle.reserve_at(later), WaitTime::ZERO);
+        assert_eq!(
+            throttle.reserve_at(later + Duration::from_millis(500)),
+            WaitTime::new(Duration::from_millis(1500))
+        );
+    }
+
+    #[test]
+    fn throttle_default_and_zero_interval() {
+        assert_eq!(Throttle::default().interval(), Throttle::DEFAULT_INTERVAL);
+        let mut unthrottled = Throttle::new(Duration::ZERO);
+        unthrottled.wait();
+        assert!(unthrottled.reserve().is_zero());
+    }
+
+    #[test]
+    fn duration_round_trip() {
+        let duration = Duration::from_millis(12_345);
+        assert_eq!(Duration::from(WaitTime::from(duration)), duration);
+        assert_eq!(WaitTime::from(duration).as_duration(), duration);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut wait = WaitTime::from_secs(60);
+        wait += Duration::from_secs(5);
+        assert_eq!(wait, WaitTime::from_secs(65));
+        assert_eq!(wait + WaitTime::from_secs(5), WaitTime::from_secs(70));
+        assert_eq!(wait - Duration::from_secs(65), WaitTime::ZERO);
+        assert!(wait.saturating_sub(Duration::from_secs(100)).is_zero());
+    }
+}